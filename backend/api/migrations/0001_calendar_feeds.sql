@@ -0,0 +1,15 @@
+-- Tokenized iCalendar feeds for groups and catechists.
+-- A feed belongs to exactly one group OR one catechist. Revoking a feed keeps the row
+-- (so we know it existed) but makes the token stop resolving.
+CREATE TABLE calendar_feeds (
+    id SERIAL PRIMARY KEY,
+    token UUID NOT NULL UNIQUE,
+    confirmation_group_id INTEGER REFERENCES confirmation_groups(id) ON DELETE CASCADE,
+    catechist_id INTEGER REFERENCES catechists(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ,
+    CHECK ((confirmation_group_id IS NULL) <> (catechist_id IS NULL))
+);
+
+CREATE INDEX calendar_feeds_group_idx ON calendar_feeds (confirmation_group_id);
+CREATE INDEX calendar_feeds_catechist_idx ON calendar_feeds (catechist_id);
@@ -0,0 +1,18 @@
+-- Parish-level calendar of dates on which groups do not meet.
+--   Range          : a one-off range of dates (start_date..end_date).
+--   Annual         : the same month/day range every year (the year of start_date/end_date is ignored).
+--                    The range may wrap around the new year, e.g. 24 Dec .. 6 Jan.
+--   EasterRelative : a range of days relative to Easter Sunday (e.g. -7..6 for Holy Week and Easter week).
+CREATE TYPE holiday_kind_enum AS ENUM ('Range', 'Annual', 'EasterRelative');
+
+CREATE TABLE parish_holidays (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    kind holiday_kind_enum NOT NULL,
+    start_date DATE,
+    end_date DATE,
+    easter_offset_start INTEGER,
+    easter_offset_end INTEGER,
+    CHECK (kind = 'EasterRelative' OR (start_date IS NOT NULL AND end_date IS NOT NULL)),
+    CHECK (kind <> 'EasterRelative' OR (easter_offset_start IS NOT NULL AND easter_offset_end IS NOT NULL))
+);
@@ -0,0 +1,31 @@
+-- Physical locations (a parish hall, a church, ...) and the rooms inside them.
+CREATE TABLE locations (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    address TEXT
+);
+
+CREATE TABLE rooms (
+    id SERIAL PRIMARY KEY,
+    location_id INTEGER NOT NULL REFERENCES locations(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    capacity INTEGER,
+    UNIQUE (location_id, name)
+);
+
+-- Groups now meet at a time of day and (optionally) in a room.
+ALTER TABLE confirmation_groups
+    ADD COLUMN start_time TIME,
+    ADD COLUMN end_time TIME,
+    ADD COLUMN room_id INTEGER REFERENCES rooms(id) ON DELETE SET NULL,
+    ADD CHECK (start_time IS NULL OR end_time IS NULL OR start_time < end_time);
+
+-- Moves a single session of a group to a different room.
+CREATE TABLE group_session_rooms (
+    confirmation_group_id INTEGER NOT NULL REFERENCES confirmation_groups(id) ON DELETE CASCADE,
+    session_date DATE NOT NULL,
+    room_id INTEGER NOT NULL REFERENCES rooms(id) ON DELETE CASCADE,
+    PRIMARY KEY (confirmation_group_id, session_date)
+);
+
+CREATE INDEX group_session_rooms_room_idx ON group_session_rooms (room_id, session_date);
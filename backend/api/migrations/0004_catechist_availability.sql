@@ -0,0 +1,13 @@
+-- Weekly availability declared by each catechist, plus how many groups they can lead at once.
+ALTER TABLE catechists ADD COLUMN max_concurrent_groups SMALLINT CHECK (max_concurrent_groups >= 0);
+
+CREATE TABLE catechist_availability (
+    id SERIAL PRIMARY KEY,
+    catechist_id INTEGER NOT NULL REFERENCES catechists(id) ON DELETE CASCADE,
+    day_of_the_week day_of_week_enum NOT NULL,
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL,
+    CHECK (start_time < end_time)
+);
+
+CREATE INDEX catechist_availability_catechist_idx ON catechist_availability (catechist_id);
@@ -0,0 +1,6 @@
+-- Weekdays on which a confirmand would prefer to meet; used when proposing group assignments.
+CREATE TABLE confirmand_preferred_days (
+    confirmand_id INTEGER NOT NULL REFERENCES confirmands(id) ON DELETE CASCADE,
+    day_of_the_week day_of_week_enum NOT NULL,
+    PRIMARY KEY (confirmand_id, day_of_the_week)
+);
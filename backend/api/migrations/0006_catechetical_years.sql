@@ -0,0 +1,16 @@
+-- Catechetical (academic) years. Groups belong to a year; at most one year is active at a time.
+CREATE TABLE catechetical_years (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE,
+    CHECK (start_date < end_date)
+);
+
+CREATE UNIQUE INDEX catechetical_years_single_active_idx ON catechetical_years (is_active) WHERE is_active;
+
+ALTER TABLE confirmation_groups
+    ADD COLUMN catechetical_year_id INTEGER REFERENCES catechetical_years(id) ON DELETE SET NULL;
+
+CREATE INDEX confirmation_groups_year_idx ON confirmation_groups (catechetical_year_id);
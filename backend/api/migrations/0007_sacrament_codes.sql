@@ -0,0 +1,25 @@
+-- Stable codes for the sacraments the application has to reason about (reports, eligibility, ceremonies).
+-- Display names stay free text; logic matches on `code`.
+ALTER TABLE sacraments ADD COLUMN code TEXT UNIQUE;
+
+-- Only the names these sacraments are known to be stored under are matched. Anything else has to
+-- be coded by hand (`UPDATE sacraments SET code = 'baptism' WHERE id = ...`) before migrating.
+UPDATE sacraments SET code = 'baptism'
+    WHERE lower(trim(name)) IN ('baptism', 'baptismo', 'batismo');
+UPDATE sacraments SET code = 'first_communion'
+    WHERE lower(trim(name)) IN ('first communion', 'holy communion', 'eucharist', 'primeira comunhão', 'primeira comunhao', 'eucaristia');
+UPDATE sacraments SET code = 'confirmation'
+    WHERE lower(trim(name)) IN ('confirmation', 'confirmação', 'confirmacao', 'crisma');
+
+DO $$
+DECLARE
+    missing TEXT;
+BEGIN
+    SELECT string_agg(expected, ', ') INTO missing
+    FROM unnest(ARRAY['baptism', 'first_communion', 'confirmation']) expected
+    WHERE NOT EXISTS (SELECT 1 FROM sacraments s WHERE s.code = expected);
+    IF missing IS NOT NULL AND EXISTS (SELECT 1 FROM sacraments) THEN
+        RAISE EXCEPTION 'No sacrament matched the code(s) %; set sacraments.code by id and run this migration again', missing;
+    END IF;
+END
+$$;
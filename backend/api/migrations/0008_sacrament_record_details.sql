@@ -0,0 +1,9 @@
+-- Parish register details for each sacrament a participant has received.
+ALTER TABLE confirmand_sacraments
+    ADD COLUMN received_date DATE,
+    ADD COLUMN church TEXT,
+    ADD COLUMN parish TEXT,
+    ADD COLUMN officiant TEXT,
+    ADD COLUMN register_book TEXT,
+    ADD COLUMN register_page TEXT,
+    ADD COLUMN register_entry TEXT;
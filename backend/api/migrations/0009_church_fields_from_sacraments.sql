@@ -0,0 +1,46 @@
+-- Sacrament records become the single source of truth for where someone was baptized and
+-- received First Communion. The free-text columns on `confirmands` are folded into
+-- `confirmand_sacraments` and replaced by a derived read-only value.
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM confirmands WHERE baptism_church IS NOT NULL)
+       AND NOT EXISTS (SELECT 1 FROM sacraments WHERE code = 'baptism') THEN
+        RAISE EXCEPTION 'No sacrament has code ''baptism''; set it before migrating baptism_church';
+    END IF;
+    IF EXISTS (SELECT 1 FROM confirmands WHERE communion_church IS NOT NULL)
+       AND NOT EXISTS (SELECT 1 FROM sacraments WHERE code = 'first_communion') THEN
+        RAISE EXCEPTION 'No sacrament has code ''first_communion''; set it before migrating communion_church';
+    END IF;
+END $$;
+
+-- A church already recorded on the sacrament wins over the legacy free-text value.
+INSERT INTO confirmand_sacraments (confirmand_id, sacrament_id, church)
+SELECT c.id, s.id, c.baptism_church
+FROM confirmands c
+INNER JOIN sacraments s ON s.code = 'baptism'
+WHERE c.baptism_church IS NOT NULL
+ON CONFLICT (confirmand_id, sacrament_id)
+    DO UPDATE SET church = COALESCE(confirmand_sacraments.church, EXCLUDED.church);
+
+INSERT INTO confirmand_sacraments (confirmand_id, sacrament_id, church)
+SELECT c.id, s.id, c.communion_church
+FROM confirmands c
+INNER JOIN sacraments s ON s.code = 'first_communion'
+WHERE c.communion_church IS NOT NULL
+ON CONFLICT (confirmand_id, sacrament_id)
+    DO UPDATE SET church = COALESCE(confirmand_sacraments.church, EXCLUDED.church);
+
+ALTER TABLE confirmands DROP COLUMN baptism_church, DROP COLUMN communion_church;
+
+-- The baptism and First Communion churches of every participant, one row each, so list queries can
+-- join them in place of the dropped columns.
+CREATE VIEW confirmand_sacrament_churches AS
+SELECT
+    cs.confirmand_id,
+    MAX(cs.church) FILTER (WHERE s.code = 'baptism') as baptism_church,
+    MAX(cs.church) FILTER (WHERE s.code = 'first_communion') as communion_church
+FROM confirmand_sacraments cs
+INNER JOIN sacraments s ON cs.sacrament_id = s.id
+WHERE s.code IN ('baptism', 'first_communion')
+GROUP BY cs.confirmand_id;
@@ -0,0 +1,21 @@
+-- Configurable checklist evaluated before someone can be confirmed.
+CREATE TYPE eligibility_requirement_kind_enum AS ENUM ('Sacrament', 'ModulesCompleted', 'Sponsor', 'MinimumAge');
+
+CREATE TABLE eligibility_requirements (
+    id SERIAL PRIMARY KEY,
+    position INTEGER NOT NULL DEFAULT 0,
+    kind eligibility_requirement_kind_enum NOT NULL,
+    label TEXT NOT NULL,
+    sacrament_code TEXT,   -- Sacrament
+    module_count SMALLINT, -- ModulesCompleted: modules 1..=module_count must be completed
+    minimum_age SMALLINT,  -- MinimumAge
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    CHECK (kind <> 'Sacrament' OR sacrament_code IS NOT NULL),
+    CHECK (kind <> 'ModulesCompleted' OR module_count > 0),
+    CHECK (kind <> 'MinimumAge' OR minimum_age >= 0)
+);
+
+INSERT INTO eligibility_requirements (position, kind, label, sacrament_code, module_count) VALUES
+    (1, 'Sacrament', 'Baptized', 'baptism', NULL),
+    (2, 'ModulesCompleted', 'Completed all modules', NULL, 3),
+    (3, 'Sponsor', 'Has a sponsor', NULL, NULL);
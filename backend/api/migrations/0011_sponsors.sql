@@ -0,0 +1,19 @@
+-- The sponsor (godparent) of a confirmand, with what is needed to check canon 874.
+CREATE TYPE sponsor_relationship_enum AS ENUM (
+    'Godparent', 'Grandparent', 'Sibling', 'Uncle/Aunt', 'Cousin', 'Friend', 'Parent', 'Other'
+);
+
+CREATE TABLE sponsors (
+    id SERIAL PRIMARY KEY,
+    confirmand_id INTEGER NOT NULL UNIQUE REFERENCES confirmands(id) ON DELETE CASCADE,
+    full_name TEXT NOT NULL,
+    email TEXT,
+    phone_number TEXT,
+    relationship sponsor_relationship_enum NOT NULL,
+    birth_date DATE NOT NULL,
+    is_confirmed BOOLEAN NOT NULL,
+    has_first_communion BOOLEAN NOT NULL,
+    marital_status marital_status_enum NOT NULL,
+    parish TEXT,
+    creation_date TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,20 @@
+-- Confirmation Masses and the confirmands who are confirmed at each of them.
+CREATE TABLE ceremonies (
+    id SERIAL PRIMARY KEY,
+    ceremony_date DATE NOT NULL,
+    church TEXT NOT NULL,
+    presiding_bishop TEXT NOT NULL,
+    finalized_at TIMESTAMPTZ
+);
+
+CREATE TABLE ceremony_candidates (
+    ceremony_id INTEGER NOT NULL REFERENCES ceremonies(id) ON DELETE CASCADE,
+    confirmand_id INTEGER NOT NULL REFERENCES confirmands(id) ON DELETE CASCADE,
+    attended BOOLEAN NOT NULL DEFAULT TRUE,
+    PRIMARY KEY (ceremony_id, confirmand_id)
+);
+
+-- Someone who missed their confirmation keeps their (absent) row on the finalized ceremony, but must
+-- be schedulable again. Finalizing is the only thing that marks a candidate absent, so a participant
+-- may only be an open candidate or have attended once.
+CREATE UNIQUE INDEX ceremony_candidates_one_active_idx ON ceremony_candidates (confirmand_id) WHERE attended;
@@ -0,0 +1,26 @@
+-- Parish header printed on every certificate. There is only ever one row.
+CREATE TABLE certificate_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    parish_name TEXT NOT NULL,
+    diocese TEXT,
+    address TEXT,
+    signatory_name TEXT,
+    signatory_title TEXT
+);
+
+INSERT INTO certificate_settings (parish_name, signatory_title) VALUES ('Parish', 'Parish Priest');
+
+-- Title and body of each kind of certificate. The body may use {placeholders}, see certificates.rs.
+CREATE TYPE certificate_kind_enum AS ENUM ('module', 'confirmation');
+
+CREATE TABLE certificate_templates (
+    kind certificate_kind_enum PRIMARY KEY,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL
+);
+
+INSERT INTO certificate_templates (kind, title, body) VALUES
+    ('module', 'Certificate of Participation',
+     E'We certify that {full_name}, born on {birth_date}, has completed the following confirmation preparation modules at {parish_name}:\n\n{modules}'),
+    ('confirmation', 'Certificate of Confirmation',
+     E'We certify that {full_name}, born on {birth_date}, received the Sacrament of Confirmation on {confirmation_date} at {confirmation_church}, conferred by {officiant}.\n\nSponsor: {sponsor_name}');
@@ -0,0 +1,60 @@
+-- Parents and guardians become people of their own, with contact data, linked to one or more
+-- confirmands. The free-text `father_name` / `mother_name` columns are folded into these links
+-- and replaced by a derived read-only value.
+CREATE TYPE guardian_relationship_enum AS ENUM (
+    'Father', 'Mother', 'Legal guardian', 'Grandparent', 'Sibling', 'Uncle/Aunt', 'Other'
+);
+
+CREATE TABLE guardians (
+    id SERIAL PRIMARY KEY,
+    full_name TEXT NOT NULL,
+    phone_number TEXT,
+    email TEXT,
+    creation_date TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE confirmand_guardians (
+    confirmand_id INTEGER NOT NULL REFERENCES confirmands(id) ON DELETE CASCADE,
+    guardian_id INTEGER NOT NULL REFERENCES guardians(id) ON DELETE CASCADE,
+    relationship guardian_relationship_enum NOT NULL,
+    is_primary_contact BOOLEAN NOT NULL DEFAULT FALSE,
+    is_consent_holder BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (confirmand_id, guardian_id)
+);
+
+-- At most one primary contact per confirmand.
+CREATE UNIQUE INDEX confirmand_guardians_one_primary_contact
+    ON confirmand_guardians (confirmand_id) WHERE is_primary_contact;
+
+-- Each legacy name becomes its own guardian; contact data can be added afterwards.
+DO $$
+DECLARE
+    c RECORD;
+    new_guardian_id INTEGER;
+BEGIN
+    FOR c IN SELECT id, father_name, mother_name FROM confirmands LOOP
+        IF NULLIF(TRIM(c.father_name), '') IS NOT NULL THEN
+            INSERT INTO guardians (full_name) VALUES (TRIM(c.father_name)) RETURNING id INTO new_guardian_id;
+            INSERT INTO confirmand_guardians (confirmand_id, guardian_id, relationship)
+            VALUES (c.id, new_guardian_id, 'Father');
+        END IF;
+        IF NULLIF(TRIM(c.mother_name), '') IS NOT NULL THEN
+            INSERT INTO guardians (full_name) VALUES (TRIM(c.mother_name)) RETURNING id INTO new_guardian_id;
+            INSERT INTO confirmand_guardians (confirmand_id, guardian_id, relationship)
+            VALUES (c.id, new_guardian_id, 'Mother');
+        END IF;
+    END LOOP;
+END $$;
+
+ALTER TABLE confirmands DROP COLUMN father_name, DROP COLUMN mother_name;
+
+-- The name of a participant's guardian with the given relationship, e.g. confirmand_guardian_name(c.id, 'Father').
+-- The primary contact wins if there are several.
+CREATE FUNCTION confirmand_guardian_name(p_confirmand_id INTEGER, p_relationship TEXT) RETURNS TEXT AS $$
+    SELECT g.full_name
+    FROM confirmand_guardians cg
+    INNER JOIN guardians g ON cg.guardian_id = g.id
+    WHERE cg.confirmand_id = p_confirmand_id AND cg.relationship::TEXT = p_relationship
+    ORDER BY cg.is_primary_contact DESC, g.id
+    LIMIT 1
+$$ LANGUAGE SQL STABLE;
@@ -0,0 +1,16 @@
+-- A household (family) groups siblings and their guardians, so that the address and contact data
+-- are kept in one place and a family can be placed and contacted as a unit.
+CREATE TABLE households (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    address TEXT,
+    phone_number TEXT,
+    email TEXT,
+    creation_date TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE confirmands ADD COLUMN household_id INTEGER REFERENCES households(id) ON DELETE SET NULL;
+ALTER TABLE guardians ADD COLUMN household_id INTEGER REFERENCES households(id) ON DELETE SET NULL;
+
+CREATE INDEX confirmands_household_id ON confirmands (household_id);
+CREATE INDEX guardians_household_id ON guardians (household_id);
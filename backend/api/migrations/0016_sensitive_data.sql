@@ -0,0 +1,31 @@
+-- Emergency contacts and medical notes, encrypted at rest with pgcrypto. The key is never stored in
+-- the database; the API passes it in from the `SENSITIVE_DATA_KEY` environment variable.
+CREATE EXTENSION IF NOT EXISTS pgcrypto;
+
+ALTER TABLE confirmands
+    ADD COLUMN emergency_contact_name BYTEA,
+    ADD COLUMN emergency_contact_phone BYTEA,
+    ADD COLUMN allergies BYTEA,
+    ADD COLUMN medical_notes BYTEA;
+
+-- A user can be linked to the catechist they are, which is how rosters know whether they are
+-- printed by one of the group's catechists.
+CREATE TYPE user_role_enum AS ENUM ('Admin', 'Coordinator', 'Secretary', 'Catechist');
+
+ALTER TABLE users
+    ADD COLUMN role user_role_enum,
+    ADD COLUMN catechist_id INTEGER REFERENCES catechists(id) ON DELETE SET NULL;
+
+-- Every account created before roles existed was an administrator, and keeps that role. New
+-- accounts start with the least privileged role until an administrator changes it.
+UPDATE users SET role = 'Admin';
+
+ALTER TABLE users
+    ALTER COLUMN role SET DEFAULT 'Catechist',
+    ALTER COLUMN role SET NOT NULL;
+
+-- Roles allowed to read emergency and medical data. Nobody is, until an administrator grants it.
+CREATE TABLE sensitive_data_grants (
+    role user_role_enum PRIMARY KEY,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
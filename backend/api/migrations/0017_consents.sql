@@ -0,0 +1,19 @@
+-- Consents given by a guardian on behalf of a confirmand. A minor needs a `Participation` consent
+-- from a guardian who is linked to them as consent holder. Guardians who gave a consent cannot be
+-- deleted, so the record of who consented is kept even after they are unlinked.
+CREATE TYPE consent_kind_enum AS ENUM (
+    'Participation', 'Data processing', 'Photos and media', 'Medical treatment', 'Outings'
+);
+
+CREATE TABLE consents (
+    id SERIAL PRIMARY KEY,
+    confirmand_id INTEGER NOT NULL REFERENCES confirmands(id) ON DELETE CASCADE,
+    guardian_id INTEGER NOT NULL REFERENCES guardians(id) ON DELETE RESTRICT,
+    kind consent_kind_enum NOT NULL,
+    given_date DATE NOT NULL,
+    -- Where the signed form is kept, e.g. a file name or archive reference.
+    document TEXT,
+    creation_date TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX consents_confirmand_id ON consents (confirmand_id);
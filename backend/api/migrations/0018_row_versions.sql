@@ -0,0 +1,55 @@
+-- A version number per confirmand, catechist and group, sent to clients as the `ETag`. Writes must
+-- carry the version they were based on in `If-Match`, so concurrent edits are refused instead of
+-- silently overwriting each other.
+ALTER TABLE confirmands ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE catechists ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE confirmation_groups ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+-- Every update bumps the version, whichever handler makes it.
+CREATE FUNCTION bump_row_version() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.version := OLD.version + 1;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER confirmands_bump_version BEFORE UPDATE ON confirmands
+    FOR EACH ROW EXECUTE FUNCTION bump_row_version();
+CREATE TRIGGER catechists_bump_version BEFORE UPDATE ON catechists
+    FOR EACH ROW EXECUTE FUNCTION bump_row_version();
+CREATE TRIGGER confirmation_groups_bump_version BEFORE UPDATE ON confirmation_groups
+    FOR EACH ROW EXECUTE FUNCTION bump_row_version();
+
+-- A participant's father and mother names and baptism and communion churches are written through
+-- their guardians and sacrament records. Changes there bump the participant's version too, so an
+-- `If-Match` based on a stale copy of those fields is refused like any other stale write.
+CREATE FUNCTION bump_confirmand_version() RETURNS TRIGGER AS $$
+BEGIN
+    -- `bump_row_version` does the actual increment on any update of the row.
+    IF TG_OP <> 'INSERT' THEN
+        UPDATE confirmands SET version = version WHERE id = OLD.confirmand_id;
+    END IF;
+    IF TG_OP <> 'DELETE' AND (TG_OP = 'INSERT' OR NEW.confirmand_id IS DISTINCT FROM OLD.confirmand_id) THEN
+        UPDATE confirmands SET version = version WHERE id = NEW.confirmand_id;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER confirmand_guardians_bump_confirmand_version AFTER INSERT OR UPDATE OR DELETE ON confirmand_guardians
+    FOR EACH ROW EXECUTE FUNCTION bump_confirmand_version();
+CREATE TRIGGER confirmand_sacraments_bump_confirmand_version AFTER INSERT OR UPDATE OR DELETE ON confirmand_sacraments
+    FOR EACH ROW EXECUTE FUNCTION bump_confirmand_version();
+
+-- A guardian's name is shown on every participant they are linked to.
+CREATE FUNCTION bump_guardian_confirmands_version() RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE confirmands SET version = version
+    WHERE id IN (SELECT confirmand_id FROM confirmand_guardians WHERE guardian_id = NEW.id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER guardians_bump_confirmand_version AFTER UPDATE ON guardians
+    FOR EACH ROW WHEN (OLD.full_name IS DISTINCT FROM NEW.full_name)
+    EXECUTE FUNCTION bump_guardian_confirmands_version();
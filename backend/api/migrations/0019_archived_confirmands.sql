@@ -0,0 +1,5 @@
+-- Deleting a participant archives them instead: the row, and the sacramental history hanging off it,
+-- stays until an administrator purges it.
+ALTER TABLE confirmands ADD COLUMN archived_at TIMESTAMPTZ;
+
+CREATE INDEX confirmands_archived_at ON confirmands (archived_at) WHERE archived_at IS NOT NULL;
@@ -0,0 +1,141 @@
+-- Append-only record of every insert, update and delete, written by triggers so no handler can
+-- forget it. The API tags each connection with the signed-in user (`crisma.user_id`); changes made
+-- outside the API are recorded without a user.
+CREATE TYPE audit_action_enum AS ENUM ('Create', 'Update', 'Delete');
+
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    -- No foreign keys: entries must outlive the users and rows they describe.
+    user_id INTEGER,
+    entity TEXT NOT NULL,
+    entity_id INTEGER,
+    -- The participant the change belongs to, when there is one.
+    confirmand_id INTEGER,
+    action audit_action_enum NOT NULL,
+    -- Updates only keep the columns that changed; creates and deletes keep the whole row.
+    before JSONB,
+    after JSONB,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_log_entity ON audit_log (entity, entity_id, changed_at);
+CREATE INDEX audit_log_confirmand_id ON audit_log (confirmand_id, changed_at) WHERE confirmand_id IS NOT NULL;
+
+CREATE FUNCTION audit_log_is_append_only() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_no_changes BEFORE UPDATE OR DELETE ON audit_log
+    FOR EACH ROW EXECUTE FUNCTION audit_log_is_append_only();
+CREATE TRIGGER audit_log_no_truncate BEFORE TRUNCATE ON audit_log
+    FOR EACH STATEMENT EXECUTE FUNCTION audit_log_is_append_only();
+
+-- Ciphertext and feed tokens are never copied into the log; only the fact that they changed is.
+CREATE FUNCTION audit_mask(data JSONB) RETURNS JSONB AS $$
+    SELECT jsonb_object_agg(
+        key,
+        CASE
+            WHEN key IN ('emergency_contact_name', 'emergency_contact_phone', 'allergies', 'medical_notes', 'token')
+                 AND value <> 'null'::JSONB
+            THEN to_jsonb('(hidden)'::TEXT)
+            ELSE value
+        END
+    )
+    FROM jsonb_each(data)
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- Trigger arguments: the entity name, the column holding its integer id ('' if it has none), and
+-- optionally the column holding the confirmand it belongs to. Ids that are not integers are not kept.
+CREATE FUNCTION audit_row_change() RETURNS TRIGGER AS $$
+DECLARE
+    old_row JSONB;
+    new_row JSONB;
+    row_data JSONB;
+    before_data JSONB;
+    after_data JSONB;
+BEGIN
+    IF TG_OP <> 'INSERT' THEN
+        old_row := to_jsonb(OLD) - 'version';
+    END IF;
+    IF TG_OP <> 'DELETE' THEN
+        new_row := to_jsonb(NEW) - 'version';
+    END IF;
+    row_data := COALESCE(new_row, old_row);
+
+    IF TG_OP = 'UPDATE' THEN
+        SELECT jsonb_object_agg(o.key, o.value), jsonb_object_agg(o.key, new_row -> o.key)
+        INTO before_data, after_data
+        FROM jsonb_each(old_row) o
+        WHERE o.value IS DISTINCT FROM new_row -> o.key;
+        IF before_data IS NULL THEN
+            RETURN NULL;
+        END IF;
+    ELSE
+        before_data := old_row;
+        after_data := new_row;
+    END IF;
+
+    INSERT INTO audit_log (user_id, entity, entity_id, confirmand_id, action, before, after)
+    VALUES (
+        NULLIF(current_setting('crisma.user_id', TRUE), '')::INTEGER,
+        TG_ARGV[0],
+        CASE WHEN TG_ARGV[1] <> '' AND row_data ->> TG_ARGV[1] ~ '^-?[0-9]+$' THEN (row_data ->> TG_ARGV[1])::INTEGER END,
+        CASE WHEN TG_NARGS > 2 THEN (row_data ->> TG_ARGV[2])::INTEGER END,
+        CASE TG_OP WHEN 'INSERT' THEN 'Create' WHEN 'UPDATE' THEN 'Update' ELSE 'Delete' END::audit_action_enum,
+        audit_mask(before_data),
+        audit_mask(after_data)
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER confirmands_audit AFTER INSERT OR UPDATE OR DELETE ON confirmands
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('confirmand', 'id', 'id');
+CREATE TRIGGER catechists_audit AFTER INSERT OR UPDATE OR DELETE ON catechists
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('catechist', 'id');
+CREATE TRIGGER confirmation_groups_audit AFTER INSERT OR UPDATE OR DELETE ON confirmation_groups
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('group', 'id');
+CREATE TRIGGER confirmand_confirmation_groups_audit AFTER INSERT OR UPDATE OR DELETE ON confirmand_confirmation_groups
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('enrollment', 'confirmation_group_id', 'confirmand_id');
+CREATE TRIGGER confirmand_sacraments_audit AFTER INSERT OR UPDATE OR DELETE ON confirmand_sacraments
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('sacrament_record', 'sacrament_id', 'confirmand_id');
+CREATE TRIGGER confirmand_preferred_days_audit AFTER INSERT OR UPDATE OR DELETE ON confirmand_preferred_days
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('preferred_day', '', 'confirmand_id');
+CREATE TRIGGER sponsors_audit AFTER INSERT OR UPDATE OR DELETE ON sponsors
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('sponsor', 'id', 'confirmand_id');
+CREATE TRIGGER guardians_audit AFTER INSERT OR UPDATE OR DELETE ON guardians
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('guardian', 'id');
+CREATE TRIGGER confirmand_guardians_audit AFTER INSERT OR UPDATE OR DELETE ON confirmand_guardians
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('guardian_link', 'guardian_id', 'confirmand_id');
+CREATE TRIGGER consents_audit AFTER INSERT OR UPDATE OR DELETE ON consents
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('consent', 'id', 'confirmand_id');
+CREATE TRIGGER households_audit AFTER INSERT OR UPDATE OR DELETE ON households
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('household', 'id');
+CREATE TRIGGER ceremonies_audit AFTER INSERT OR UPDATE OR DELETE ON ceremonies
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('ceremony', 'id');
+CREATE TRIGGER ceremony_candidates_audit AFTER INSERT OR UPDATE OR DELETE ON ceremony_candidates
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('ceremony_candidate', 'ceremony_id', 'confirmand_id');
+CREATE TRIGGER catechist_availability_audit AFTER INSERT OR UPDATE OR DELETE ON catechist_availability
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('catechist_availability', 'catechist_id');
+CREATE TRIGGER catechetical_years_audit AFTER INSERT OR UPDATE OR DELETE ON catechetical_years
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('catechetical_year', 'id');
+CREATE TRIGGER locations_audit AFTER INSERT OR UPDATE OR DELETE ON locations
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('location', 'id');
+CREATE TRIGGER rooms_audit AFTER INSERT OR UPDATE OR DELETE ON rooms
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('room', 'id');
+CREATE TRIGGER group_session_rooms_audit AFTER INSERT OR UPDATE OR DELETE ON group_session_rooms
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('session_room', 'confirmation_group_id');
+CREATE TRIGGER parish_holidays_audit AFTER INSERT OR UPDATE OR DELETE ON parish_holidays
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('parish_holiday', 'id');
+CREATE TRIGGER calendar_feeds_audit AFTER INSERT OR UPDATE OR DELETE ON calendar_feeds
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('calendar_feed', 'id');
+CREATE TRIGGER eligibility_requirements_audit AFTER INSERT OR UPDATE OR DELETE ON eligibility_requirements
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('eligibility_requirement', 'id');
+CREATE TRIGGER certificate_settings_audit AFTER INSERT OR UPDATE OR DELETE ON certificate_settings
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('certificate_settings', '');
+CREATE TRIGGER certificate_templates_audit AFTER INSERT OR UPDATE OR DELETE ON certificate_templates
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('certificate_template', '');
+CREATE TRIGGER sensitive_data_grants_audit AFTER INSERT OR UPDATE OR DELETE ON sensitive_data_grants
+    FOR EACH ROW EXECUTE FUNCTION audit_row_change('sensitive_data_grant', '');
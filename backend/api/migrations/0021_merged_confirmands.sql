@@ -0,0 +1,3 @@
+-- A duplicate merged into another participant is archived rather than deleted, and points at the
+-- participant it was merged into, so the merge can be traced and the duplicate is never restored.
+ALTER TABLE confirmands ADD COLUMN merged_into INTEGER REFERENCES confirmands(id) ON DELETE SET NULL;
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use chrono::{Datelike, NaiveDate};
+use crate::models::{AssignmentProposal, DayOfTheWeek, ProposedAssignment, UnplacedConfirmand};
+
+// A confirmand waiting for a group, and the module they should take next.
+pub struct Candidate {
+    pub id: i32,
+    pub full_name: String,
+    pub age: i32,
+    pub module: i16,
+    pub preferred_days: Vec<DayOfTheWeek>,
+    pub household_id: Option<i32>,
+}
+
+// A group that can still take members, with its current size and age profile.
+pub struct OpenGroup {
+    pub id: i32,
+    pub module: i16,
+    pub day_of_the_week: DayOfTheWeek,
+    pub member_count: i64,
+    pub age_sum: f64,
+}
+
+impl OpenGroup {
+    fn mean_age(&self) -> Option<f64> {
+        if self.member_count == 0 { None } else { Some(self.age_sum / self.member_count as f64) }
+    }
+}
+
+// Age in whole years on a given date.
+pub fn age_on(birth_date: NaiveDate, on: NaiveDate) -> i32 {
+    let mut age = on.year() - birth_date.year();
+    if (on.month(), on.day()) < (birth_date.month(), birth_date.day()) {
+        age -= 1;
+    }
+    age
+}
+
+// The module a participant should take next, given the last one they took (`None` if they never had
+// a group) and the highest module any group was ever created for. `None` once they completed that one:
+// there is no further module to put them in.
+pub fn next_module(last_module: Option<i16>, highest_module: Option<i16>) -> Option<i16> {
+    match (last_module, highest_module) {
+        (Some(last), Some(highest)) if last >= highest => None,
+        (last, _) => Some(last.map_or(1, |m| m + 1)),
+    }
+}
+
+// Greedily distributes candidates over the open groups of their module.
+//
+// The most constrained candidates (fewest suitable groups) are placed first. Each candidate goes to
+// the smallest group on one of their preferred weekdays, falling back to any weekday if none of those
+// has room; ties are broken by how close the group's mean age is to the candidate's age. Groups are
+// never filled beyond `target_group_size`. Siblings (same household) taking the same module join the
+// group the first of them was placed in, as long as it has room.
+pub fn propose(mut candidates: Vec<Candidate>, mut groups: Vec<OpenGroup>, target_group_size: i64) -> AssignmentProposal {
+    let suitable = |c: &Candidate, groups: &[OpenGroup]| groups.iter().filter(|g| g.module == c.module).count();
+    candidates.sort_by_key(|c| (suitable(c, &groups), c.preferred_days.len(), c.age));
+
+    let mut assignments = Vec::new();
+    let mut unplaced = Vec::new();
+    let mut family_groups: HashMap<(i32, i16), usize> = HashMap::new();
+
+    for candidate in candidates {
+        let with_room: Vec<usize> = (0..groups.len())
+            .filter(|&i| groups[i].module == candidate.module && groups[i].member_count < target_group_size)
+            .collect();
+
+        if with_room.is_empty() {
+            let reason = if groups.iter().any(|g| g.module == candidate.module) {
+                format!("All open groups for module {} are full", candidate.module)
+            } else {
+                format!("There is no open group for module {}", candidate.module)
+            };
+            unplaced.push(UnplacedConfirmand { confirmand_id: candidate.id, full_name: candidate.full_name, reason });
+            continue;
+        }
+
+        let preferred: Vec<usize> = with_room
+            .iter()
+            .copied()
+            .filter(|&i| candidate.preferred_days.contains(&groups[i].day_of_the_week))
+            .collect();
+        let pool = if preferred.is_empty() { &with_room } else { &preferred };
+
+        let age_distance = |g: &OpenGroup| g.mean_age().map_or(0.0, |mean| (mean - candidate.age as f64).abs());
+        let family_key = candidate.household_id.map(|household| (household, candidate.module));
+        let sibling_group = family_key
+            .and_then(|key| family_groups.get(&key).copied())
+            .filter(|i| with_room.contains(i));
+        let best = sibling_group.unwrap_or_else(|| {
+            *pool
+                .iter()
+                .min_by(|&&a, &&b| {
+                    groups[a].member_count.cmp(&groups[b].member_count)
+                        .then(age_distance(&groups[a]).total_cmp(&age_distance(&groups[b])))
+                })
+                .expect("pool is not empty")
+        });
+        let preference_met =
+            candidate.preferred_days.is_empty() || candidate.preferred_days.contains(&groups[best].day_of_the_week);
+        if let Some(key) = family_key {
+            family_groups.entry(key).or_insert(best);
+        }
+
+        let group = &mut groups[best];
+        group.member_count += 1;
+        group.age_sum += candidate.age as f64;
+
+        assignments.push(ProposedAssignment {
+            confirmand_id: candidate.id,
+            full_name: candidate.full_name,
+            age: candidate.age,
+            group_id: group.id,
+            module: group.module,
+            day_of_the_week: group.day_of_the_week.to_string(),
+            preference_met,
+        });
+    }
+
+    AssignmentProposal { assignments, unplaced }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: i32, age: i32, module: i16, preferred_days: Vec<DayOfTheWeek>) -> Candidate {
+        Candidate { id, full_name: format!("Participant {}", id), age, module, preferred_days, household_id: None }
+    }
+
+    fn sibling(id: i32, age: i32, module: i16, household_id: i32) -> Candidate {
+        Candidate { household_id: Some(household_id), ..candidate(id, age, module, vec![]) }
+    }
+
+    fn group(id: i32, module: i16, day_of_the_week: DayOfTheWeek, member_count: i64, mean_age: f64) -> OpenGroup {
+        OpenGroup { id, module, day_of_the_week, member_count, age_sum: mean_age * member_count as f64 }
+    }
+
+    fn group_of(proposal: &AssignmentProposal, confirmand_id: i32) -> Option<i32> {
+        proposal.assignments.iter().find(|a| a.confirmand_id == confirmand_id).map(|a| a.group_id)
+    }
+
+    #[test]
+    fn age_on_counts_whole_years() {
+        let birth_date = NaiveDate::from_ymd_opt(2010, 6, 15).unwrap();
+        assert_eq!(age_on(birth_date, NaiveDate::from_ymd_opt(2025, 6, 14).unwrap()), 14);
+        assert_eq!(age_on(birth_date, NaiveDate::from_ymd_opt(2025, 6, 15).unwrap()), 15);
+    }
+
+    #[test]
+    fn next_module_stops_after_the_highest_one() {
+        assert_eq!(next_module(None, None), Some(1));
+        assert_eq!(next_module(None, Some(3)), Some(1));
+        assert_eq!(next_module(Some(2), Some(3)), Some(3));
+        assert_eq!(next_module(Some(3), Some(3)), None);
+    }
+
+    #[test]
+    fn places_candidates_only_in_groups_of_their_module() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 0, 0.0), group(2, 2, DayOfTheWeek::Monday, 0, 0.0)];
+        let proposal = propose(vec![candidate(10, 14, 2, vec![]), candidate(11, 13, 3, vec![])], groups, 10);
+        assert_eq!(group_of(&proposal, 10), Some(2));
+        assert_eq!(proposal.unplaced.len(), 1);
+        assert_eq!(proposal.unplaced[0].confirmand_id, 11);
+        assert_eq!(proposal.unplaced[0].reason, "There is no open group for module 3");
+    }
+
+    #[test]
+    fn prefers_the_requested_weekday_and_falls_back_to_any_day() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 0, 0.0), group(2, 1, DayOfTheWeek::Saturday, 5, 14.0)];
+        let proposal = propose(
+            vec![candidate(10, 14, 1, vec![DayOfTheWeek::Saturday]), candidate(11, 14, 1, vec![DayOfTheWeek::Friday])],
+            groups,
+            10,
+        );
+        assert_eq!(group_of(&proposal, 10), Some(2));
+        assert!(proposal.assignments.iter().find(|a| a.confirmand_id == 10).unwrap().preference_met);
+        // Nobody meets on Friday, so the smallest group is used.
+        assert_eq!(group_of(&proposal, 11), Some(1));
+        assert!(!proposal.assignments.iter().find(|a| a.confirmand_id == 11).unwrap().preference_met);
+    }
+
+    #[test]
+    fn breaks_size_ties_by_mean_age() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 4, 16.0), group(2, 1, DayOfTheWeek::Monday, 4, 13.0)];
+        let proposal = propose(vec![candidate(10, 13, 1, vec![])], groups, 10);
+        assert_eq!(group_of(&proposal, 10), Some(2));
+    }
+
+    #[test]
+    fn never_fills_a_group_beyond_the_target_size() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 1, 14.0)];
+        let proposal = propose(vec![candidate(10, 14, 1, vec![]), candidate(11, 14, 1, vec![])], groups, 2);
+        assert_eq!(proposal.assignments.len(), 1);
+        assert_eq!(proposal.unplaced.len(), 1);
+        assert_eq!(proposal.unplaced[0].reason, "All open groups for module 1 are full");
+    }
+
+    #[test]
+    fn keeps_siblings_in_the_same_group() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 0, 0.0), group(2, 1, DayOfTheWeek::Monday, 0, 0.0)];
+        let proposal = propose(vec![sibling(10, 13, 1, 7), sibling(11, 14, 1, 7), candidate(12, 13, 1, vec![])], groups, 10);
+        assert_eq!(group_of(&proposal, 10), group_of(&proposal, 11));
+        assert_ne!(group_of(&proposal, 10), group_of(&proposal, 12));
+    }
+
+    #[test]
+    fn splits_siblings_when_their_group_is_full() {
+        let groups = vec![group(1, 1, DayOfTheWeek::Monday, 0, 0.0), group(2, 1, DayOfTheWeek::Monday, 0, 0.0)];
+        let proposal = propose(vec![sibling(10, 13, 1, 7), sibling(11, 14, 1, 7)], groups, 1);
+        assert_eq!(proposal.assignments.len(), 2);
+        assert_ne!(group_of(&proposal, 10), group_of(&proposal, 11));
+    }
+}
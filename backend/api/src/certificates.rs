@@ -0,0 +1,284 @@
+use std::io::{Cursor, Write};
+use chrono::NaiveDate;
+use printpdf::{BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+use zip::{write::SimpleFileOptions, ZipWriter};
+use crate::models::{CertificateKind, CertificateSettings, CertificateTemplate, ConfirmandDetails};
+
+// A4 portrait, in millimetres.
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 25.0;
+// Points to millimetres.
+const PT: f32 = 0.3528;
+
+// Collects the values a template can refer to as `{name}`. Fails with a human readable reason
+// when the participant does not (yet) qualify for this kind of certificate.
+pub fn placeholder_values(
+    kind: &CertificateKind,
+    details: &ConfirmandDetails,
+    settings: &CertificateSettings,
+    today: NaiveDate,
+) -> Result<Vec<(&'static str, String)>, String> {
+    let confirmand = &details.confirmand;
+    let mut values = vec![
+        ("full_name", confirmand.full_name.clone()),
+        ("birth_date", format_date(confirmand.birth_date)),
+        ("parish_name", settings.parish_name.clone()),
+        ("issue_date", format_date(today)),
+        ("sponsor_name", details.sponsor.as_ref().map(|s| s.full_name.clone()).unwrap_or_else(|| "-".to_string())),
+    ];
+
+    match kind {
+        CertificateKind::Module => {
+            let mut completed: Vec<_> = details
+                .group_history
+                .iter()
+                .filter(|g| g.end_date.is_some_and(|end| end < today))
+                .collect();
+            if completed.is_empty() {
+                return Err(format!("{} has not completed any module yet", confirmand.full_name));
+            }
+            completed.sort_by_key(|g| (g.module, g.start_date));
+            let lines: Vec<String> = completed
+                .iter()
+                .map(|g| {
+                    let period = format!("{} - {}", format_date(g.start_date), g.end_date.map(format_date).unwrap_or_default());
+                    match &g.catechist_name {
+                        Some(name) => format!("Module {} ({}), catechist {}", g.module, period, name),
+                        None => format!("Module {} ({})", g.module, period),
+                    }
+                })
+                .collect();
+            values.push(("modules", lines.join("\n")));
+        }
+        CertificateKind::Confirmation => {
+            let record = details
+                .sacraments
+                .iter()
+                .find(|s| s.sacrament.code.as_deref() == Some("confirmation"))
+                .ok_or_else(|| format!("{} has not been confirmed", confirmand.full_name))?;
+            let unknown = || "-".to_string();
+            values.push(("confirmation_date", record.details.received_date.map(format_date).unwrap_or_else(unknown)));
+            values.push(("confirmation_church", record.details.church.clone().unwrap_or_else(unknown)));
+            values.push(("officiant", record.details.officiant.clone().unwrap_or_else(unknown)));
+            values.push(("register_book", record.details.register_book.clone().unwrap_or_else(unknown)));
+            values.push(("register_page", record.details.register_page.clone().unwrap_or_else(unknown)));
+            values.push(("register_entry", record.details.register_entry.clone().unwrap_or_else(unknown)));
+        }
+    }
+    Ok(values)
+}
+
+// Replaces every `{name}` in the template text. Unknown placeholders are left as they are.
+pub fn fill(text: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(text.to_string(), |acc, (name, value)| acc.replace(&format!("{{{}}}", name), value))
+}
+
+// Renders a one-page certificate with the parish header, the filled-in template and a signature line.
+pub fn render(
+    settings: &CertificateSettings,
+    template: &CertificateTemplate,
+    values: &[(&str, String)],
+) -> Result<Vec<u8>, printpdf::Error> {
+    let title = fill(&template.title, values);
+    let (doc, page, layer) = PdfDocument::new(&title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Certificate");
+    let regular = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+    let layer = doc.get_page(page).get_layer(layer);
+
+    // Header
+    let mut y = PAGE_HEIGHT - MARGIN;
+    layer.use_text(settings.parish_name.as_str(), 16.0, Mm(MARGIN), Mm(y), &bold);
+    for line in [&settings.diocese, &settings.address].into_iter().flatten() {
+        y -= 6.0;
+        layer.use_text(line.as_str(), 10.0, Mm(MARGIN), Mm(y), &regular);
+    }
+    y -= 5.0;
+    rule(&layer, y, MARGIN, PAGE_WIDTH - MARGIN);
+
+    // Title and body
+    y -= 25.0;
+    layer.use_text(title.as_str(), 24.0, Mm(MARGIN), Mm(y), &bold);
+    y -= 15.0;
+    y = paragraph(&layer, &regular, &fill(&template.body, values), 12.0, y);
+
+    // Place, date and signature
+    let issued = values.iter().find(|(name, _)| *name == "issue_date").map(|(_, v)| v.as_str()).unwrap_or_default();
+    y = y.min(PAGE_HEIGHT / 3.0) - 10.0;
+    layer.use_text(format!("{}, {}", settings.parish_name, issued), 11.0, Mm(MARGIN), Mm(y), &regular);
+    y -= 30.0;
+    let signature_x = PAGE_WIDTH / 2.0 + 10.0;
+    rule(&layer, y, signature_x, PAGE_WIDTH - MARGIN);
+    for line in [&settings.signatory_name, &settings.signatory_title].into_iter().flatten() {
+        y -= 5.5;
+        layer.use_text(line.as_str(), 10.0, Mm(signature_x), Mm(y), &regular);
+    }
+
+    doc.save_to_bytes()
+}
+
+// Packs several PDFs into a single zip archive.
+pub fn zip_files(files: Vec<(String, Vec<u8>)>) -> zip::result::ZipResult<Vec<u8>> {
+    let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, bytes) in files {
+        archive.start_file(name, SimpleFileOptions::default())?;
+        archive.write_all(&bytes)?;
+    }
+    Ok(archive.finish()?.into_inner())
+}
+
+// A safe file name such as `12-maria-silva-confirmation.pdf`.
+pub fn file_name(confirmand_id: i32, full_name: &str, kind: &CertificateKind) -> String {
+    let ascii: String = full_name.to_lowercase().chars().map(fold_accent).collect();
+    let slug: Vec<&str> = ascii
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect();
+    format!("{}-{}-{}.pdf", confirmand_id, slug.join("-"), kind)
+}
+
+// Keeps file names ASCII so that every browser saves them correctly.
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        'ñ' => 'n',
+        other => other,
+    }
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+    date.format("%d/%m/%Y").to_string()
+}
+
+pub fn rule(layer: &PdfLayerReference, y: f32, from_x: f32, to_x: f32) {
+    layer.set_outline_thickness(0.5);
+    layer.add_line(Line {
+        points: vec![(Point::new(Mm(from_x), Mm(y)), false), (Point::new(Mm(to_x), Mm(y)), false)],
+        is_closed: false,
+    });
+}
+
+// Writes word-wrapped text and returns the y position below it. The built-in fonts carry no
+// metrics, so lines are wrapped using Helvetica's average character width.
+fn paragraph(layer: &PdfLayerReference, font: &IndirectFontRef, text: &str, size: f32, mut y: f32) -> f32 {
+    let line_height = size * PT * 1.5;
+    let max_chars = ((PAGE_WIDTH - 2.0 * MARGIN) / (size * PT * 0.5)) as usize;
+    for source_line in text.lines() {
+        if source_line.trim().is_empty() {
+            y -= line_height;
+            continue;
+        }
+        let mut current = String::new();
+        for word in source_line.split_whitespace() {
+            if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_chars {
+                layer.use_text(current.as_str(), size, Mm(MARGIN), Mm(y), font);
+                y -= line_height;
+                current.clear();
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        layer.use_text(current.as_str(), size, Mm(MARGIN), Mm(y), font);
+        y -= line_height;
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Confirmand, GroupSummary, Sacrament, SacramentRecord, SacramentRecordDetails};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn details(group_history: Vec<GroupSummary>, sacraments: Vec<SacramentRecord>) -> ConfirmandDetails {
+        ConfirmandDetails {
+            confirmand: Confirmand::sample(12, "Maria Conceição", date(2010, 3, 4)),
+            sacraments,
+            group_history,
+            sponsor: None,
+            guardians: Vec::new(),
+            household: None,
+        }
+    }
+
+    fn settings() -> CertificateSettings {
+        CertificateSettings {
+            parish_name: "Paróquia da Sé".to_string(),
+            diocese: None,
+            address: None,
+            signatory_name: None,
+            signatory_title: None,
+        }
+    }
+
+    fn value<'a>(values: &'a [(&str, String)], name: &str) -> &'a str {
+        values.iter().find(|(n, _)| *n == name).map(|(_, v)| v.as_str()).unwrap()
+    }
+
+    #[test]
+    fn fills_known_placeholders_and_leaves_the_rest() {
+        let values = [("full_name", "Maria".to_string()), ("issue_date", "01/06/2026".to_string())];
+        assert_eq!(
+            fill("{full_name}, {full_name} on {issue_date} by {officiant}", &values),
+            "Maria, Maria on 01/06/2026 by {officiant}"
+        );
+    }
+
+    #[test]
+    fn lists_completed_modules_only() {
+        let group = |module: i16, start_date, end_date| GroupSummary { id: 1, module, start_date, end_date, catechist_name: None };
+        let history = vec![
+            group(2, date(2025, 10, 1), Some(date(2026, 6, 30))),
+            group(1, date(2024, 10, 1), Some(date(2025, 6, 30))),
+        ];
+        let values = placeholder_values(&CertificateKind::Module, &details(history, Vec::new()), &settings(), date(2026, 1, 1)).unwrap();
+        assert_eq!(value(&values, "modules"), "Module 1 (01/10/2024 - 30/06/2025)");
+        assert_eq!(value(&values, "sponsor_name"), "-");
+        assert_eq!(value(&values, "issue_date"), "01/01/2026");
+
+        let error = placeholder_values(&CertificateKind::Module, &details(Vec::new(), Vec::new()), &settings(), date(2026, 1, 1));
+        assert_eq!(error.unwrap_err(), "Maria Conceição has not completed any module yet");
+    }
+
+    #[test]
+    fn shows_missing_confirmation_details_as_a_dash() {
+        let confirmation = SacramentRecord {
+            sacrament: Sacrament { id: 4, name: "Confirmation".to_string(), code: Some("confirmation".to_string()) },
+            details: SacramentRecordDetails {
+                received_date: Some(date(2026, 5, 24)),
+                church: Some("Sé".to_string()),
+                parish: None,
+                officiant: None,
+                register_book: None,
+                register_page: None,
+                register_entry: None,
+            },
+        };
+        let values = placeholder_values(&CertificateKind::Confirmation, &details(Vec::new(), vec![confirmation]), &settings(), date(2026, 6, 1)).unwrap();
+        assert_eq!(value(&values, "confirmation_date"), "24/05/2026");
+        assert_eq!(value(&values, "officiant"), "-");
+
+        let error = placeholder_values(&CertificateKind::Confirmation, &details(Vec::new(), Vec::new()), &settings(), date(2026, 6, 1));
+        assert_eq!(error.unwrap_err(), "Maria Conceição has not been confirmed");
+    }
+
+    #[test]
+    fn folds_accents_and_punctuation_in_file_names() {
+        assert_eq!(file_name(12, "Maria Conceição", &CertificateKind::Confirmation), "12-maria-conceicao-confirmation.pdf");
+        assert_eq!(file_name(3, "  João D'Ávila-Muñoz ", &CertificateKind::Module), "3-joao-d-avila-munoz-module.pdf");
+        assert_eq!(fold_accent('ç'), 'c');
+        assert_eq!(fold_accent('ß'), 'ß');
+    }
+}
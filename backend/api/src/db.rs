@@ -1,4 +1,4 @@
-use deadpool_postgres::{Manager, Pool, Runtime};
+use deadpool_postgres::{Manager, ManagerConfig, Pool, RecyclingMethod, Runtime};
 use std::str::FromStr; // <-- IMPORT THE FromStr TRAIT
 use tokio_postgres::NoTls;
 
@@ -13,7 +13,10 @@ pub fn create_pool() -> Result<DBPool, Box<dyn std::error::Error>> {
 
     // 2. Create a Manager. We pass it the tokio_postgres::Config and NoTls.
     //    The manager is what knows how to create and recycle connections.
-    let manager = Manager::new(pg_config, NoTls);
+    //    Connections are cleaned when they go back to the pool, so session settings such as the
+    //    audit user (`crisma.user_id`) never leak into the next request.
+    let manager_config = ManagerConfig { recycling_method: RecyclingMethod::Clean };
+    let manager = Manager::from_config(pg_config, NoTls, manager_config);
 
     // 3. Create the Pool using the manager. This is the new, correct way.
     //    We also configure the pool size here.
@@ -0,0 +1,191 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::{
+    models::{Confirmand, DuplicateCandidate},
+    validation::normalize_phone,
+};
+
+// Names must be at least this similar before anything else is looked at, so twins and siblings
+// sharing a birth date or a phone number are not flagged.
+const MIN_NAME_SIMILARITY: f64 = 0.8;
+// Pairs scoring below this are not worth a coordinator's time.
+const MIN_SCORE: f64 = 0.7;
+const NAME_WEIGHT: f64 = 0.5;
+const BIRTH_DATE_WEIGHT: f64 = 0.3;
+const CONTACT_WEIGHT: f64 = 0.2;
+
+// Lists pairs of participants that are probably the same person, most likely first. Only pairs
+// sharing a normalized name, a birth date or a phone number are compared.
+pub fn find(confirmands: &[Confirmand]) -> Vec<DuplicateCandidate> {
+    let names: Vec<String> = confirmands.iter().map(|c| normalize_name(&c.full_name)).collect();
+    let phones: Vec<Option<String>> = confirmands.iter().map(|c| phone_key(&c.phone_number)).collect();
+
+    let mut blocks: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, confirmand) in confirmands.iter().enumerate() {
+        blocks.entry(format!("name:{}", names[index])).or_default().push(index);
+        blocks.entry(format!("birth:{}", confirmand.birth_date)).or_default().push(index);
+        if let Some(phone) = &phones[index] {
+            blocks.entry(format!("phone:{}", phone)).or_default().push(index);
+        }
+    }
+    let pairs: BTreeSet<(usize, usize)> = blocks
+        .values()
+        .flat_map(|block| {
+            block.iter().enumerate().flat_map(move |(i, &a)| block[i + 1..].iter().map(move |&b| (a, b)))
+        })
+        .collect();
+
+    let mut candidates: Vec<DuplicateCandidate> = pairs
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let (first, second) = (&confirmands[a], &confirmands[b]);
+            let name_similarity = name_similarity(&names[a], &names[b]);
+            if name_similarity < MIN_NAME_SIMILARITY {
+                return None;
+            }
+            let mut reasons = vec![if names[a] == names[b] { "Same name" } else { "Similar names" }.to_string()];
+            let mut score = NAME_WEIGHT * name_similarity;
+            if first.birth_date == second.birth_date {
+                score += BIRTH_DATE_WEIGHT;
+                reasons.push("Same birth date".to_string());
+            }
+            let same_phone = phones[a].is_some() && phones[a] == phones[b];
+            let same_email = !first.email.is_empty() && first.email.eq_ignore_ascii_case(&second.email);
+            if same_phone {
+                reasons.push("Same phone number".to_string());
+            }
+            if same_email {
+                reasons.push("Same email".to_string());
+            }
+            if same_phone || same_email {
+                score += CONTACT_WEIGHT;
+            }
+            (score >= MIN_SCORE).then(|| DuplicateCandidate {
+                first: first.clone(),
+                second: second.clone(),
+                score: (score * 100.0).round() / 100.0,
+                reasons,
+            })
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.first.id.cmp(&b.first.id)));
+    candidates
+}
+
+// Lowercase, without accents or punctuation, single-spaced: "  João  D'Ávila " becomes "joao d avila".
+pub fn normalize_name(name: &str) -> String {
+    let folded: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// 1.0 for identical names. Otherwise the better of the edit-distance ratio, which catches typos, and
+// the share of the shorter name's words found in the other, which catches a missing middle name.
+fn name_similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let longest = a.chars().count().max(b.chars().count());
+    let edit_ratio = if longest == 0 { 0.0 } else { 1.0 - levenshtein(a, b) as f64 / longest as f64 };
+
+    let a_words: BTreeSet<&str> = a.split(' ').collect();
+    let b_words: BTreeSet<&str> = b.split(' ').collect();
+    let shared = a_words.intersection(&b_words).count();
+    // A single shared word is usually just a common surname.
+    let word_ratio = if shared >= 2 { shared as f64 / a_words.len().min(b_words.len()) as f64 } else { 0.0 };
+
+    edit_ratio.max(word_ratio)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// Phone numbers compare in E.164 when they can be normalized, by their digits otherwise.
+fn phone_key(phone: &str) -> Option<String> {
+    normalize_phone(phone).ok().or_else(|| {
+        let digits: String = phone.chars().filter(char::is_ascii_digit).collect();
+        (!digits.is_empty()).then_some(digits)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn confirmand(id: i32, full_name: &str, birth_date: NaiveDate, phone_number: &str, email: &str) -> Confirmand {
+        Confirmand {
+            phone_number: phone_number.to_string(),
+            email: email.to_string(),
+            ..Confirmand::sample(id, full_name, birth_date)
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn normalizes_accents_case_and_punctuation() {
+        assert_eq!(normalize_name("  João  D'Ávila "), "joao d avila");
+        assert_eq!(normalize_name("ANA-SOFIA Conceição"), "ana sofia conceicao");
+    }
+
+    #[test]
+    fn finds_the_same_person_entered_twice() {
+        let confirmands = vec![
+            confirmand(1, "João Silva", date(2010, 3, 4), "912 345 678", "joao@example.pt"),
+            confirmand(2, "Joao Silva", date(2010, 3, 4), "+351912345678", "joao.silva@example.pt"),
+            confirmand(3, "Maria Sousa", date(2011, 7, 1), "933 000 111", "maria@example.pt"),
+        ];
+        let candidates = find(&confirmands);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!((candidates[0].first.id, candidates[0].second.id), (1, 2));
+        assert_eq!(candidates[0].score, 1.0);
+        assert_eq!(candidates[0].reasons, vec!["Same name", "Same birth date", "Same phone number"]);
+    }
+
+    #[test]
+    fn tolerates_typos_and_missing_middle_names() {
+        let confirmands = vec![
+            confirmand(1, "Ana Maria Ferreira", date(2010, 3, 4), "", "a@example.pt"),
+            confirmand(2, "Ana Ferreira", date(2010, 3, 4), "", "b@example.pt"),
+            confirmand(3, "Ana Ferriera", date(2010, 3, 4), "", "c@example.pt"),
+        ];
+        let pairs: Vec<(i32, i32)> = find(&confirmands).iter().map(|c| (c.first.id, c.second.id)).collect();
+        // "Ana Maria Ferreira" and "Ana Ferriera" share only their first name.
+        assert_eq!(pairs, vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn does_not_flag_twins_sharing_a_phone() {
+        let confirmands = vec![
+            confirmand(1, "Rita Costa", date(2010, 3, 4), "912345678", "home@example.pt"),
+            confirmand(2, "Tiago Costa", date(2010, 3, 4), "912345678", "home@example.pt"),
+        ];
+        assert!(find(&confirmands).is_empty());
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use crate::{
+    assignment::age_on,
+    models::{
+        ConfirmandDetails, EligibilityReport, EligibilityRequirement, GroupSummary, RequirementKind, RequirementResult,
+        RequirementStatus, SacramentRecord,
+    },
+};
+
+// The parts of a participant the checklist looks at. Borrowed from their full details, or loaded in
+// bulk when many participants are checked at once.
+pub struct Participant<'a> {
+    pub id: i32,
+    pub full_name: &'a str,
+    pub birth_date: NaiveDate,
+    pub sacraments: &'a [SacramentRecord],
+    pub group_history: &'a [GroupSummary],
+    pub sponsor_name: Option<&'a str>,
+}
+
+impl<'a> From<&'a ConfirmandDetails> for Participant<'a> {
+    fn from(details: &'a ConfirmandDetails) -> Self {
+        Self {
+            id: details.confirmand.id,
+            full_name: &details.confirmand.full_name,
+            birth_date: details.confirmand.birth_date,
+            sacraments: &details.sacraments,
+            group_history: &details.group_history,
+            sponsor_name: details.sponsor.as_ref().map(|s| s.full_name.as_str()),
+        }
+    }
+}
+
+// Evaluates the enabled requirements of the checklist against a participant.
+// Someone is eligible only if every enabled requirement is met.
+pub fn evaluate<'a>(participant: impl Into<Participant<'a>>, requirements: &[EligibilityRequirement], today: NaiveDate) -> EligibilityReport {
+    let details = participant.into();
+    let results: Vec<RequirementResult> = requirements
+        .iter()
+        .filter(|r| r.enabled)
+        .map(|requirement| {
+            let (status, detail) = check(&details, requirement, today);
+            RequirementResult {
+                requirement_id: requirement.id,
+                kind: requirement.kind.clone(),
+                label: requirement.label.clone(),
+                status,
+                detail,
+            }
+        })
+        .collect();
+
+    EligibilityReport {
+        confirmand_id: details.id,
+        full_name: details.full_name.to_string(),
+        eligible: results.iter().all(|r| r.status == RequirementStatus::Met),
+        requirements: results,
+    }
+}
+
+fn check(details: &Participant, requirement: &EligibilityRequirement, today: NaiveDate) -> (RequirementStatus, Option<String>) {
+    match requirement.kind {
+        RequirementKind::Sacrament => {
+            let code = requirement.sacrament_code.as_deref().unwrap_or_default();
+            match details.sacraments.iter().find(|s| s.sacrament.code.as_deref() == Some(code)) {
+                Some(record) => {
+                    let place = record.details.church.as_deref().or(record.details.parish.as_deref());
+                    let detail = match (record.details.received_date, place) {
+                        (Some(date), Some(place)) => Some(format!("Received on {} at {}", date, place)),
+                        (Some(date), None) => Some(format!("Received on {}", date)),
+                        (None, Some(place)) => Some(format!("Received at {}", place)),
+                        (None, None) => None,
+                    };
+                    (RequirementStatus::Met, detail)
+                }
+                None => (RequirementStatus::NotMet, Some(format!("No {} record", code))),
+            }
+        }
+        RequirementKind::ModulesCompleted => {
+            let module_count = requirement.module_count.unwrap_or(0);
+            // A module counts as completed once the participant's group for it has ended.
+            let completed: BTreeSet<i16> = details
+                .group_history
+                .iter()
+                .filter(|g| g.end_date.is_some_and(|end| end < today))
+                .map(|g| g.module)
+                .collect();
+            let missing: Vec<String> = (1..=module_count).filter(|m| !completed.contains(m)).map(|m| m.to_string()).collect();
+            if missing.is_empty() {
+                (RequirementStatus::Met, None)
+            } else {
+                (RequirementStatus::NotMet, Some(format!("Modules not completed: {}", missing.join(", "))))
+            }
+        }
+        RequirementKind::MinimumAge => {
+            let minimum_age = requirement.minimum_age.unwrap_or(0) as i32;
+            let age = age_on(details.birth_date, today);
+            let status = if age >= minimum_age { RequirementStatus::Met } else { RequirementStatus::NotMet };
+            (status, Some(format!("Age {} (minimum {})", age, minimum_age)))
+        }
+        RequirementKind::Sponsor => match details.sponsor_name {
+            Some(sponsor) => (RequirementStatus::Met, Some(sponsor.to_string())),
+            None => (RequirementStatus::NotMet, Some("No sponsor recorded".to_string())),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Confirmand, Sacrament, SacramentRecordDetails, Sponsor};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn today() -> NaiveDate {
+        date(2026, 6, 1)
+    }
+
+    fn details(birth_date: NaiveDate) -> ConfirmandDetails {
+        ConfirmandDetails {
+            confirmand: Confirmand::sample(1, "Ana Silva", birth_date),
+            sacraments: Vec::new(),
+            group_history: Vec::new(),
+            sponsor: None,
+            guardians: Vec::new(),
+            household: None,
+        }
+    }
+
+    fn requirement(id: i32, kind: RequirementKind) -> EligibilityRequirement {
+        EligibilityRequirement {
+            id,
+            label: kind.to_string(),
+            kind,
+            sacrament_code: None,
+            module_count: None,
+            minimum_age: None,
+            enabled: true,
+        }
+    }
+
+    fn group(module: i16, end_date: Option<NaiveDate>) -> GroupSummary {
+        GroupSummary { id: module as i32, module, start_date: date(2020, 9, 1), end_date, catechist_name: None }
+    }
+
+    fn baptism(received_date: Option<NaiveDate>, church: Option<&str>) -> SacramentRecord {
+        SacramentRecord {
+            sacrament: Sacrament { id: 1, name: "Baptism".to_string(), code: Some("baptism".to_string()) },
+            details: SacramentRecordDetails {
+                received_date,
+                church: church.map(str::to_string),
+                parish: None,
+                officiant: None,
+                register_book: None,
+                register_page: None,
+                register_entry: None,
+            },
+        }
+    }
+
+    #[test]
+    fn sacrament_requirement_needs_a_record_with_that_code() {
+        let mut baptized = requirement(1, RequirementKind::Sacrament);
+        baptized.sacrament_code = Some("baptism".to_string());
+        let mut participant = details(date(2012, 1, 1));
+
+        let report = evaluate(&participant, std::slice::from_ref(&baptized), today());
+        assert!(!report.eligible);
+        assert_eq!(report.requirements[0].detail.as_deref(), Some("No baptism record"));
+
+        participant.sacraments.push(baptism(Some(date(2012, 5, 6)), Some("Sé de Braga")));
+        let report = evaluate(&participant, &[baptized], today());
+        assert!(report.eligible);
+        assert_eq!(report.requirements[0].detail.as_deref(), Some("Received on 2012-05-06 at Sé de Braga"));
+    }
+
+    #[test]
+    fn modules_count_as_completed_once_their_group_has_ended() {
+        let mut all_modules = requirement(2, RequirementKind::ModulesCompleted);
+        all_modules.module_count = Some(3);
+        let mut participant = details(date(2012, 1, 1));
+        participant.group_history = vec![
+            group(1, Some(date(2024, 6, 1))),
+            group(2, Some(date(2025, 6, 1))),
+            group(3, Some(date(2026, 6, 30))),
+        ];
+
+        let report = evaluate(&participant, std::slice::from_ref(&all_modules), today());
+        assert_eq!(report.requirements[0].status, RequirementStatus::NotMet);
+        assert_eq!(report.requirements[0].detail.as_deref(), Some("Modules not completed: 3"));
+
+        let report = evaluate(&participant, &[all_modules], date(2026, 7, 1));
+        assert!(report.eligible);
+    }
+
+    #[test]
+    fn minimum_age_is_checked_on_the_given_day() {
+        let mut old_enough = requirement(3, RequirementKind::MinimumAge);
+        old_enough.minimum_age = Some(14);
+        let participant = details(date(2012, 6, 2));
+
+        let report = evaluate(&participant, std::slice::from_ref(&old_enough), today());
+        assert!(!report.eligible);
+        assert_eq!(report.requirements[0].detail.as_deref(), Some("Age 13 (minimum 14)"));
+        assert!(evaluate(&participant, &[old_enough], date(2026, 6, 2)).eligible);
+    }
+
+    #[test]
+    fn disabled_requirements_are_ignored() {
+        let mut sponsor = requirement(4, RequirementKind::Sponsor);
+        let participant = details(date(2012, 1, 1));
+        assert!(!evaluate(&participant, std::slice::from_ref(&sponsor), today()).eligible);
+
+        sponsor.enabled = false;
+        let report = evaluate(&participant, &[sponsor], today());
+        assert!(report.eligible);
+        assert!(report.requirements.is_empty());
+    }
+
+    #[test]
+    fn sponsor_requirement_reports_the_sponsor_name() {
+        let mut participant = details(date(2012, 1, 1));
+        participant.sponsor = Some(Sponsor {
+            id: 1,
+            confirmand_id: 1,
+            full_name: "Maria Costa".to_string(),
+            email: None,
+            phone_number: None,
+            relationship: "Aunt".to_string(),
+            birth_date: date(1985, 3, 3),
+            is_confirmed: true,
+            has_first_communion: true,
+            marital_status: "Married".to_string(),
+            parish: None,
+        });
+        let report = evaluate(&participant, &[requirement(4, RequirementKind::Sponsor)], today());
+        assert!(report.eligible);
+        assert_eq!(report.requirements[0].detail.as_deref(), Some("Maria Costa"));
+    }
+}
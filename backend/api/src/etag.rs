@@ -0,0 +1,64 @@
+use axum::http::{header, HeaderMap, StatusCode};
+
+// The `ETag` for a row version, e.g. `"3"`.
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+// The version a write was based on, from its `If-Match` header. `*` matches any version and gives
+// `None`. Writes without the header are refused with `428 Precondition Required`.
+pub fn if_match(headers: &HeaderMap) -> Result<Option<i32>, (StatusCode, String)> {
+    let value = headers
+        .get(header::IF_MATCH)
+        .ok_or((StatusCode::PRECONDITION_REQUIRED, "An If-Match header with the record's ETag is required".to_string()))?;
+    let malformed = || (StatusCode::BAD_REQUEST, "If-Match must be an ETag such as \"3\"".to_string());
+    let value = value.to_str().map_err(|_| malformed())?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .and_then(|version| version.parse().ok())
+        .map(Some)
+        .ok_or_else(malformed)
+}
+
+// Fails with `412 Precondition Failed` when the record changed since the client read it.
+pub fn check(current: i32, expected: Option<i32>) -> Result<(), (StatusCode, String)> {
+    match expected {
+        Some(expected) if expected != current => Err((
+            StatusCode::PRECONDITION_FAILED,
+            format!("The record was changed by someone else (now at {}); reload it and try again", etag(current)),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(if_match: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_str(if_match).unwrap());
+        headers
+    }
+
+    #[test]
+    fn parses_if_match() {
+        assert_eq!(if_match(&headers("\"3\"")).unwrap(), Some(3));
+        assert_eq!(if_match(&headers("*")).unwrap(), None);
+        assert_eq!(if_match(&headers("3")).unwrap_err().0, StatusCode::BAD_REQUEST);
+        assert_eq!(if_match(&headers("W/\"3\"")).unwrap_err().0, StatusCode::BAD_REQUEST);
+        assert_eq!(if_match(&HeaderMap::new()).unwrap_err().0, StatusCode::PRECONDITION_REQUIRED);
+    }
+
+    #[test]
+    fn refuses_stale_versions() {
+        assert!(check(4, Some(4)).is_ok());
+        assert!(check(4, None).is_ok());
+        assert_eq!(check(5, Some(4)).unwrap_err().0, StatusCode::PRECONDITION_FAILED);
+    }
+}
@@ -1,50 +1,71 @@
-use axum::{extract::{Path, State}, http::StatusCode, Json, body::Body, BoxError};
-use crate::{models::{User, GroupSummary, DashboardStats, MaritalStatus, Confirmand, CreateConfirmand, Catechist, CreateCatechist, CatechistDetails, ConfirmationGroup, CreateConfirmationGroup, AddParticipantToGroup, ConfirmationGroupDetails, Sacrament, ConfirmandDetails, UpdateParticipantSacrament}, AppState, auth::AuthenticatedUser};
+use axum::{extract::{Path, Query, State}, http::{header, HeaderMap, StatusCode}, response::IntoResponse, Json, body::Body, BoxError};
+use crate::{models::{User, GroupSummary, DashboardStats, MaritalStatus, DayOfTheWeek, Confirmand, CreateConfirmand, Catechist, CreateCatechist, CatechistDetails, ConfirmationGroup, CreateConfirmationGroup, AddParticipantToGroup, ConfirmationGroupDetails, Sacrament, ConfirmandDetails, UpdateParticipantSacrament, CalendarFeed, CreateParishHoliday, ParishHoliday, GroupSchedule, AffectedSession, Location, CreateLocation, Room, CreateRoom, AssignRoom, SessionRoom, RoomBookingCheck, RoomConflict, AvailabilitySlot, CatechistAvailability, AvailabilityQuery, ProposeAssignments, AssignmentProposal, ApplyAssignments, CatecheticalYear, CreateCatecheticalYear, YearFilter, YearRollover, ReportsOverview, ModuleEnrollment, MonthlyCount, ModuleOutcome, YearlyCount, AgeBucket, SacramentRecord, SacramentRecordDetails, CreateEligibilityRequirement, EligibilityRequirement, EligibilityReport, Sponsor, UpsertSponsor, RequirementStatus, Ceremony, CreateCeremony, CeremonyCandidate, CeremonyDetails, AddCeremonyCandidates, FinalizeCeremony, CertificateKind, CertificateSettings, CertificateTemplate, UpdateCertificateTemplate, GuardianRelationship, LinkGuardian, UpdateGuardian, Guardian, CreateHousehold, Household, HouseholdMember, HouseholdDetails, AddHouseholdMembers, FamilyContact, UserRole, SensitiveDataGrants, EmergencyInfo, ConsentKind, CreateConsent, GuardianConsent, Consent, ADULT_AGE, PatchConfirmand, AuditQuery, AuditEntry, HistoryEvent, DuplicateCandidate, MergeConfirmands, UserAccount, UpdateUserAccess}, AppState, auth::AuthenticatedUser, ical::{self, WeeklyEvent}, schedule, assignment::{self, Candidate, OpenGroup}, eligibility, certificates, roster, validation::{FieldErrors, Validate}, etag, history, duplicates};
 use csv::ReaderBuilder; // --- NEW ---
 use std::io::Cursor; // --- NEW ---
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, Utc};
 use serde_json::json; // --- NEW ---
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
+use tokio_postgres::{Client, Row, Transaction};
+use uuid::Uuid;
 
 // MODIFIED: The SELECT query now LEFT JOINs to find the current group for each participant.
-pub async fn list_confirmands(user: AuthenticatedUser, State(state): State<AppState>) -> Result<Json<Vec<Confirmand>>, (StatusCode, String)> {
+pub async fn list_confirmands(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(filter): Query<YearFilter>,
+) -> Result<Json<Vec<Confirmand>>, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_confirmands(&conn, filter.year).await?))
+}
 
+// Participants who are not archived, with their most recent group as the current one.
+async fn fetch_confirmands(conn: &Client, year: Option<i32>) -> Result<Vec<Confirmand>, (StatusCode, String)> {
     // --- MODIFICATION: The SQL query now also selects the group's start_date ---
     let sql = "
         SELECT DISTINCT ON (c.id)
             c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-            c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+            c.birth_date, c.address, c.version, c.archived_at,
+            confirmand_guardian_name(c.id, 'Father') as father_name,
+            confirmand_guardian_name(c.id, 'Mother') as mother_name,
+            sc.baptism_church, sc.communion_church,
             cg.id as current_group_id,
             cg.module as current_group_module,
             cg.start_date as current_group_start_date -- Added this line
         FROM confirmands c
+        LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
         LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
         LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+        WHERE c.archived_at IS NULL
+          AND ($1::INT IS NULL OR cg.catechetical_year_id = $1) -- With a year, only its participants and groups
         ORDER BY c.id, cg.start_date DESC
     ";
 
-    let rows = conn.query(sql, &[]).await.map_err(internal_error)?;
-    let confirmands: Vec<Confirmand> = rows.into_iter().map(Confirmand::from).collect();
-    Ok(Json(confirmands))
+    let rows = conn.query(sql, &[&year]).await.map_err(internal_error)?;
+    Ok(rows.into_iter().map(Confirmand::from).collect())
 }
 // MODIFICATION: The INSERT and RETURNING statements now include all columns.
 pub async fn create_confirmand(
     user: AuthenticatedUser,
     State(state): State<AppState>,
-    Json(payload): Json<CreateConfirmand>,
+    Json(mut payload): Json<CreateConfirmand>,
 ) -> Result<(StatusCode, Json<Confirmand>), (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
 
     // Step 1: Insert the new record and return its ID. This part is correct.
     let insert_sql = "
         INSERT INTO confirmands (
-            full_name, birth_date, address, phone_number, email, marital_status, 
-            father_name, mother_name, baptism_church, communion_church
+            full_name, birth_date, address, phone_number, email, marital_status
         ) 
-        VALUES ($1, $2, $3, $4, $5, CAST($6 AS VARCHAR)::marital_status_enum, $7, $8, $9, $10) 
+        VALUES ($1, $2, $3, $4, $5, CAST($6 AS VARCHAR)::marital_status_enum) 
         RETURNING id
     ";
-    let row = conn
+    let row = transaction
         .query_one(
             insert_sql,
             &[
@@ -54,10 +75,6 @@ pub async fn create_confirmand(
                 &payload.phone_number,
                 &payload.email,
                 &payload.marital_status.to_string(),
-                &payload.father_name,
-                &payload.mother_name,
-                &payload.baptism_church,
-                &payload.communion_church,
             ],
         )
         .await
@@ -65,16 +82,33 @@ pub async fn create_confirmand(
     
     let new_id: i32 = row.get(0);
 
+    // The legacy church fields are stored on the matching sacrament records.
+    set_sacrament_church(&transaction, new_id, "baptism", &payload.baptism_church).await?;
+    set_sacrament_church(&transaction, new_id, "first_communion", &payload.communion_church).await?;
+    // The legacy parent names are stored as linked guardians.
+    set_legacy_guardian(&transaction, new_id, GuardianRelationship::Father, &payload.father_name).await?;
+    set_legacy_guardian(&transaction, new_id, GuardianRelationship::Mother, &payload.mother_name).await?;
+    // Minors cannot be registered without a guardian's consent.
+    if let Some(consent) = &payload.guardian_consent {
+        record_guardian_consent(&transaction, new_id, consent).await?;
+    }
+    require_guardian_consent(&transaction, new_id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+
     // Step 2: Fetch the complete, newly created record.
     // THIS QUERY IS NOW CORRECTED to match the one in `list_confirmands`.
     let select_sql = "
         SELECT 
             c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-            c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+            c.birth_date, c.address, c.version, c.archived_at,
+            confirmand_guardian_name(c.id, 'Father') as father_name,
+            confirmand_guardian_name(c.id, 'Mother') as mother_name,
+            sc.baptism_church, sc.communion_church,
             cg.id as current_group_id,
             cg.module as current_group_module,
             cg.start_date as current_group_start_date -- This was the missing line
         FROM confirmands c
+        LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
         LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
         LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
         WHERE c.id = $1
@@ -89,30 +123,97 @@ pub async fn update_confirmand(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path(id): Path<i32>,
-    Json(payload): Json<CreateConfirmand>,
-) -> Result<Json<Confirmand>, (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+    headers: HeaderMap,
+    Json(mut payload): Json<CreateConfirmand>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found to update", id)).await?;
+    let current = fetch_confirmand(&transaction, id).await?;
+    save_confirmand(&transaction, id, &payload, current.birth_date != payload.birth_date).await?;
+    transaction.commit().await.map_err(internal_error)?;
+
+    let confirmand = fetch_confirmand(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(confirmand.version))], Json(confirmand)))
+}
+
+// Handler for `PATCH /api/confirmands/:id`
+// Only the fields sent are changed. The row is locked while the patch is merged into the stored
+// record, so concurrent patches to other fields are not lost.
+pub async fn patch_confirmand(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    Json(mut patch): Json<PatchConfirmand>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    patch.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found to update", id)).await?;
+
+    let current = fetch_confirmand(&transaction, id).await?;
+    let birth_date_changed = patch.birth_date.flatten().is_some_and(|date| date != current.birth_date);
+    let mut payload = patch.apply_to(current).map_err(internal_error)?;
+    payload.validate(Utc::now().date_naive())?;
+    save_confirmand(&transaction, id, &payload, birth_date_changed).await?;
+    transaction.commit().await.map_err(internal_error)?;
+
+    let confirmand = fetch_confirmand(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(confirmand.version))], Json(confirmand)))
+}
 
-    // Step 1: Perform the UPDATE. We don't need a complex RETURNING clause.
+// Locks a confirmand, catechist or group row and checks it is still at the version the client
+// based its write on (`If-Match`). `table` is always one of our own table names.
+async fn lock_version(
+    transaction: &Transaction<'_>,
+    table: &str,
+    id: i32,
+    expected: Option<i32>,
+    not_found: String,
+) -> Result<(), (StatusCode, String)> {
+    let sql = format!("SELECT version FROM {} WHERE id = $1 FOR UPDATE", table);
+    let row = transaction
+        .query_opt(&sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, not_found))?;
+    etag::check(row.get("version"), expected)
+}
+
+// The version of a row after this transaction's writes, sent back as the response's `ETag`.
+async fn current_version(transaction: &Transaction<'_>, table: &str, id: i32) -> Result<i32, (StatusCode, String)> {
+    let sql = format!("SELECT version FROM {} WHERE id = $1", table);
+    let row = transaction.query_one(&sql, &[&id]).await.map_err(internal_error)?;
+    Ok(row.get("version"))
+}
+
+// Writes a full, validated record, including the fields that live in other tables. The minor
+// consent rule is only checked when the birth date changes or a consent is sent, so participants
+// registered before the rule existed can still be edited.
+async fn save_confirmand(
+    transaction: &Transaction<'_>,
+    id: i32,
+    payload: &CreateConfirmand,
+    birth_date_changed: bool,
+) -> Result<(), (StatusCode, String)> {
     let update_sql = "
         UPDATE confirmands 
         SET 
            full_name = $1, birth_date = $2, address = $3, phone_number = $4, email = $5, 
-           marital_status = CAST($6 AS VARCHAR)::marital_status_enum,
-           father_name = $7, mother_name = $8, baptism_church = $9, communion_church = $10
-        WHERE id = $11
+           marital_status = CAST($6 AS VARCHAR)::marital_status_enum
+        WHERE id = $7
     ";
-    let result = conn.execute(update_sql, &[
+    let result = transaction.execute(update_sql, &[
         &payload.full_name,
         &payload.birth_date,
         &payload.address,
         &payload.phone_number,
         &payload.email,
         &payload.marital_status.to_string(),
-        &payload.father_name,
-        &payload.mother_name,
-        &payload.baptism_church,
-        &payload.communion_church,
         &id,
     ]).await.map_err(internal_error)?;
 
@@ -120,40 +221,527 @@ pub async fn update_confirmand(
         return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found to update", id)));
     }
 
-    // Step 2: Fetch the complete, updated record with the JOIN to get all fields, including group info.
+    set_sacrament_church(transaction, id, "baptism", &payload.baptism_church).await?;
+    set_sacrament_church(transaction, id, "first_communion", &payload.communion_church).await?;
+    set_legacy_guardian(transaction, id, GuardianRelationship::Father, &payload.father_name).await?;
+    set_legacy_guardian(transaction, id, GuardianRelationship::Mother, &payload.mother_name).await?;
+    if let Some(consent) = &payload.guardian_consent {
+        record_guardian_consent(transaction, id, consent).await?;
+    }
+    if birth_date_changed || payload.guardian_consent.is_some() {
+        require_guardian_consent(transaction, id).await?;
+    }
+    Ok(())
+}
+
+// A single confirmand, with their most recent group as the current one.
+async fn fetch_confirmand(conn: &impl deadpool_postgres::GenericClient, id: i32) -> Result<Confirmand, (StatusCode, String)> {
     let select_sql = "
-        SELECT 
+        SELECT DISTINCT ON (c.id)
             c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-            c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+            c.birth_date, c.address, c.version, c.archived_at,
+            confirmand_guardian_name(c.id, 'Father') as father_name,
+            confirmand_guardian_name(c.id, 'Mother') as mother_name,
+            sc.baptism_church, sc.communion_church,
             cg.id as current_group_id,
-            cg.module as current_group_module
+            cg.module as current_group_module,
+            cg.start_date as current_group_start_date
         FROM confirmands c
+        LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
         LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
         LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
         WHERE c.id = $1
+        ORDER BY c.id, cg.start_date DESC
     ";
-    let updated_row = conn.query_one(select_sql, &[&id]).await.map_err(internal_error)?;
-    
-    let updated_confirmand = Confirmand::from(updated_row);
-    Ok(Json(updated_confirmand))
+    let row = conn
+        .query_opt(select_sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)))?;
+    Ok(Confirmand::from(row))
+}
+
+// Writes one of the legacy church fields (`baptism_church`, `communion_church`) through to the
+// sacrament record it now lives on. A church implies the sacrament was received, so the record is
+// created if needed; clearing the field only clears the church and keeps the record. A church for a
+// sacrament no one has given its code yet is refused rather than dropped.
+async fn set_sacrament_church(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    sacrament_code: &str,
+    church: &Option<String>,
+) -> Result<(), (StatusCode, String)> {
+    match church {
+        Some(church) => {
+            let sql = "
+                INSERT INTO confirmand_sacraments (confirmand_id, sacrament_id, church)
+                SELECT $1, id, $3 FROM sacraments WHERE code = $2
+                ON CONFLICT (confirmand_id, sacrament_id) DO UPDATE SET church = EXCLUDED.church
+            ";
+            let written = transaction.execute(sql, &[&confirmand_id, &sacrament_code, church]).await.map_err(internal_error)?;
+            if written == 0 {
+                let field = if sacrament_code == "baptism" { "baptism_church" } else { "communion_church" };
+                let mut errors = FieldErrors::default();
+                errors.add(field, format!("cannot be stored: no sacrament has the code '{}'", sacrament_code));
+                errors.into_result()?;
+            }
+        }
+        None => {
+            let sql = "
+                UPDATE confirmand_sacraments cs SET church = NULL
+                FROM sacraments s
+                WHERE cs.sacrament_id = s.id AND s.code = $2 AND cs.confirmand_id = $1
+            ";
+            transaction.execute(sql, &[&confirmand_id, &sacrament_code]).await.map_err(internal_error)?;
+        }
+    }
+    Ok(())
+}
+
+// Writes one of the legacy parent names (`father_name`, `mother_name`) through to the guardian
+// linked with that relationship, creating the guardian if there is none yet. Clearing the name
+// unlinks the guardian; guardians left without any confirmand are removed.
+async fn set_legacy_guardian(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    relationship: GuardianRelationship,
+    full_name: &Option<String>,
+) -> Result<(), (StatusCode, String)> {
+    let linked = transaction
+        .query_opt(
+            "SELECT guardian_id FROM confirmand_guardians
+             WHERE confirmand_id = $1 AND relationship = CAST($2 AS VARCHAR)::guardian_relationship_enum
+             ORDER BY is_primary_contact DESC, guardian_id
+             LIMIT 1",
+            &[&confirmand_id, &relationship.to_string()],
+        )
+        .await
+        .map_err(internal_error)?
+        .map(|row| row.get::<_, i32>("guardian_id"));
+
+    match (full_name.as_deref().map(str::trim).filter(|name| !name.is_empty()), linked) {
+        (Some(name), Some(guardian_id)) => {
+            transaction
+                .execute("UPDATE guardians SET full_name = $1 WHERE id = $2", &[&name, &guardian_id])
+                .await
+                .map_err(internal_error)?;
+        }
+        (Some(name), None) => {
+            let row = transaction
+                .query_one("INSERT INTO guardians (full_name) VALUES ($1) RETURNING id", &[&name])
+                .await
+                .map_err(internal_error)?;
+            let guardian_id: i32 = row.get("id");
+            transaction
+                .execute(
+                    "INSERT INTO confirmand_guardians (confirmand_id, guardian_id, relationship)
+                     VALUES ($1, $2, CAST($3 AS VARCHAR)::guardian_relationship_enum)",
+                    &[&confirmand_id, &guardian_id, &relationship.to_string()],
+                )
+                .await
+                .map_err(internal_error)?;
+        }
+        (None, Some(guardian_id)) => {
+            unlink_guardian(transaction, confirmand_id, guardian_id).await?;
+        }
+        (None, None) => {}
+    }
+    Ok(())
+}
+
+// Removes the link and the guardian itself once no confirmand refers to them anymore. Guardians who
+// gave a consent are kept so that the consent record stays complete.
+async fn unlink_guardian(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    guardian_id: i32,
+) -> Result<u64, (StatusCode, String)> {
+    let unlinked = transaction
+        .execute(
+            "DELETE FROM confirmand_guardians WHERE confirmand_id = $1 AND guardian_id = $2",
+            &[&confirmand_id, &guardian_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction
+        .execute(
+            "DELETE FROM guardians g WHERE g.id = $1
+             AND NOT EXISTS (SELECT 1 FROM confirmand_guardians cg WHERE cg.guardian_id = g.id)
+             AND NOT EXISTS (SELECT 1 FROM consents co WHERE co.guardian_id = g.id)",
+            &[&guardian_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(unlinked)
 }
 
+// Handler for `DELETE /api/confirmands/:id`
+// Archives the participant instead of deleting them: sacramental history has to be kept. Archived
+// participants are hidden from listings and can be restored; see `purge_confirmand` for real deletion.
 pub async fn delete_confirmand(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<StatusCode, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+    let archived = transaction
+        .execute("UPDATE confirmands SET archived_at = NOW() WHERE id = $1 AND archived_at IS NULL", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if archived == 0 {
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} is already archived", id)));
+    }
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `GET /api/confirmands/duplicates`
+// Pairs of participants who are probably the same person, for a coordinator to review and merge.
+pub async fn find_duplicate_confirmands(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<DuplicateCandidate>>, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
-    let result = conn
-        .execute("DELETE FROM confirmands WHERE id = $1", &[&id])
+    let confirmands = fetch_confirmands(&conn, None).await?;
+    Ok(Json(duplicates::find(&confirmands)))
+}
+
+// Handler for `POST /api/confirmands/:id/merge`
+// Folds a duplicate into this participant and archives it, marked as merged into this one. Group
+// enrollments, sacrament records, guardians, consents, preferred days and places on open ceremonies
+// move over; candidacies on finalized ceremonies are part of a closed record and stay with the
+// duplicate. Where both have something, this participant's data wins and only its blanks are
+// filled in from the duplicate: the household, the sponsor, the sacrament record details, and
+// each of the emergency contact name and phone, allergies and medical notes (nothing is
+// concatenated). Enrollments and sacrament records are moved rather than copied, so they keep
+// their history. Refused with `409 Conflict` when the two are in different groups of the same
+// module, or are candidates on different open ceremonies.
+pub async fn merge_confirmands(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<MergeConfirmands>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let duplicate_id = payload.duplicate_id;
+    if duplicate_id == id {
+        return Err((StatusCode::BAD_REQUEST, "A participant cannot be merged into itself".to_string()));
+    }
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let rows: HashMap<i32, Row> = transaction
+        .query(
+            "SELECT id, archived_at IS NOT NULL as archived, merged_into
+             FROM confirmands WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+            &[&vec![id, duplicate_id]],
+        )
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| (row.get("id"), row))
+        .collect();
+    for participant_id in [id, duplicate_id] {
+        if !rows.contains_key(&participant_id) {
+            return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", participant_id)));
+        }
+    }
+    if rows[&id].get::<_, bool>("archived") {
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} is archived; restore it before merging into it", id)));
+    }
+    if let Some(merged_into) = rows[&duplicate_id].get::<_, Option<i32>>("merged_into") {
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} was already merged into {}", duplicate_id, merged_into)));
+    }
+
+    let same_module = transaction
+        .query_opt(
+            "SELECT g.module, g.id as group_id, d.id as duplicate_group_id
+             FROM confirmand_confirmation_groups a
+             INNER JOIN confirmation_groups g ON a.confirmation_group_id = g.id
+             INNER JOIN confirmand_confirmation_groups b ON b.confirmand_id = $2
+             INNER JOIN confirmation_groups d ON b.confirmation_group_id = d.id
+             WHERE a.confirmand_id = $1 AND g.module = d.module AND g.id <> d.id
+             LIMIT 1",
+            &[&id, &duplicate_id],
+        )
         .await
         .map_err(internal_error)?;
-    if result == 0 {
-        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)));
+    if let Some(row) = same_module {
+        return Err((
+            StatusCode::CONFLICT,
+            format!(
+                "Both participants are enrolled in module {} but in different groups ({} and {}); move one of them first",
+                row.get::<_, i16>("module"),
+                row.get::<_, i32>("group_id"),
+                row.get::<_, i32>("duplicate_group_id"),
+            ),
+        ));
+    }
+
+    // Locked so none of them is finalized halfway through the merge.
+    let open_ceremonies: Vec<i32> = transaction
+        .query(
+            "SELECT id FROM ceremonies
+             WHERE id IN (SELECT ceremony_id FROM ceremony_candidates WHERE confirmand_id = $1)
+               AND finalized_at IS NULL
+             ORDER BY id FOR UPDATE",
+            &[&duplicate_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    // A participant may only be an open candidate or have attended once.
+    let other_ceremony = transaction
+        .query_opt(
+            "SELECT s.ceremony_id FROM ceremony_candidates s
+             WHERE s.confirmand_id = $1 AND s.attended
+               AND EXISTS (
+                    SELECT 1 FROM ceremony_candidates d
+                    WHERE d.confirmand_id = $2 AND d.attended AND d.ceremony_id = ANY($3) AND d.ceremony_id <> s.ceremony_id
+               )",
+            &[&id, &duplicate_id, &open_ceremonies],
+        )
+        .await
+        .map_err(internal_error)?;
+    if let Some(row) = other_ceremony {
+        return Err((
+            StatusCode::CONFLICT,
+            format!(
+                "Participant with ID {} is already on ceremony {} and the duplicate is a candidate on another; remove one of them first",
+                id,
+                row.get::<_, i32>("ceremony_id"),
+            ),
+        ));
+    }
+
+    let statements = [
+        // Blanks on the survivor are filled in from the duplicate.
+        "UPDATE confirmands c SET
+            household_id = COALESCE(c.household_id, d.household_id),
+            emergency_contact_name = COALESCE(c.emergency_contact_name, d.emergency_contact_name),
+            emergency_contact_phone = COALESCE(c.emergency_contact_phone, d.emergency_contact_phone),
+            allergies = COALESCE(c.allergies, d.allergies),
+            medical_notes = COALESCE(c.medical_notes, d.medical_notes)
+         FROM confirmands d
+         WHERE c.id = $1 AND d.id = $2",
+        "UPDATE confirmand_confirmation_groups d SET confirmand_id = $1
+         WHERE d.confirmand_id = $2 AND NOT EXISTS (
+            SELECT 1 FROM confirmand_confirmation_groups s
+            WHERE s.confirmand_id = $1 AND s.confirmation_group_id = d.confirmation_group_id
+         )",
+        "UPDATE confirmand_sacraments cs SET
+            received_date = COALESCE(cs.received_date, d.received_date),
+            church = COALESCE(cs.church, d.church),
+            parish = COALESCE(cs.parish, d.parish),
+            officiant = COALESCE(cs.officiant, d.officiant),
+            register_book = COALESCE(cs.register_book, d.register_book),
+            register_page = COALESCE(cs.register_page, d.register_page),
+            register_entry = COALESCE(cs.register_entry, d.register_entry)
+         FROM confirmand_sacraments d
+         WHERE cs.confirmand_id = $1 AND d.confirmand_id = $2 AND cs.sacrament_id = d.sacrament_id",
+        "UPDATE confirmand_sacraments d SET confirmand_id = $1
+         WHERE d.confirmand_id = $2 AND NOT EXISTS (
+            SELECT 1 FROM confirmand_sacraments s WHERE s.confirmand_id = $1 AND s.sacrament_id = d.sacrament_id
+         )",
+        // The survivor keeps its primary contact if it has one.
+        "INSERT INTO confirmand_guardians (confirmand_id, guardian_id, relationship, is_primary_contact, is_consent_holder)
+         SELECT $1, guardian_id, relationship,
+                is_primary_contact AND NOT EXISTS (
+                    SELECT 1 FROM confirmand_guardians WHERE confirmand_id = $1 AND is_primary_contact
+                ),
+                is_consent_holder
+         FROM confirmand_guardians WHERE confirmand_id = $2
+         ON CONFLICT DO NOTHING",
+        "UPDATE consents SET confirmand_id = $1 WHERE confirmand_id = $2",
+        "UPDATE sponsors SET confirmand_id = $1
+         WHERE confirmand_id = $2 AND NOT EXISTS (SELECT 1 FROM sponsors WHERE confirmand_id = $1)",
+        "INSERT INTO confirmand_preferred_days (confirmand_id, day_of_the_week)
+         SELECT $1, day_of_the_week FROM confirmand_preferred_days WHERE confirmand_id = $2
+         ON CONFLICT DO NOTHING",
+    ];
+    for sql in statements {
+        transaction.execute(sql, &[&id, &duplicate_id]).await.map_err(internal_error)?;
+    }
+    let ceremony_statements = [
+        "UPDATE ceremony_candidates s SET attended = s.attended OR d.attended
+         FROM ceremony_candidates d
+         WHERE s.confirmand_id = $1 AND d.confirmand_id = $2 AND s.ceremony_id = d.ceremony_id AND s.ceremony_id = ANY($3)",
+        "UPDATE ceremony_candidates d SET confirmand_id = $1
+         WHERE d.confirmand_id = $2 AND d.ceremony_id = ANY($3) AND NOT EXISTS (
+            SELECT 1 FROM ceremony_candidates s WHERE s.confirmand_id = $1 AND s.ceremony_id = d.ceremony_id
+         )",
+    ];
+    for sql in ceremony_statements {
+        transaction.execute(sql, &[&id, &duplicate_id, &open_ceremonies]).await.map_err(internal_error)?;
+    }
+    // Left only where both were on the same list.
+    transaction
+        .execute(
+            "DELETE FROM ceremony_candidates WHERE confirmand_id = $1 AND ceremony_id = ANY($2)",
+            &[&duplicate_id, &open_ceremonies],
+        )
+        .await
+        .map_err(internal_error)?;
+    // Whatever was not moved stays with the duplicate, which is archived (if it was not already).
+    transaction
+        .execute(
+            "UPDATE confirmands SET archived_at = COALESCE(archived_at, NOW()), merged_into = $1 WHERE id = $2",
+            &[&id, &duplicate_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+
+    let confirmand = fetch_confirmand(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(confirmand.version))], Json(confirmand)))
+}
+
+// Handler for `GET /api/confirmands/archived`
+pub async fn list_archived_confirmands(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Confirmand>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = "
+        SELECT * FROM (
+            SELECT DISTINCT ON (c.id)
+                c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
+                c.birth_date, c.address, c.version, c.archived_at,
+                confirmand_guardian_name(c.id, 'Father') as father_name,
+                confirmand_guardian_name(c.id, 'Mother') as mother_name,
+                sc.baptism_church, sc.communion_church,
+                cg.id as current_group_id,
+                cg.module as current_group_module,
+                cg.start_date as current_group_start_date
+            FROM confirmands c
+            LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
+            LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
+            LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+            WHERE c.archived_at IS NOT NULL
+            ORDER BY c.id, cg.start_date DESC
+        ) archived
+        ORDER BY archived_at DESC
+    ";
+    let rows = conn.query(sql, &[]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Confirmand::from).collect()))
+}
+
+// Handler for `POST /api/confirmands/:id/restore`
+pub async fn restore_confirmand(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let restored = conn
+        .execute(
+            "UPDATE confirmands SET archived_at = NULL WHERE id = $1 AND archived_at IS NOT NULL AND merged_into IS NULL",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?;
+    if restored == 0 {
+        let merged_into: Option<i32> = conn
+            .query_opt("SELECT merged_into FROM confirmands WHERE id = $1", &[&id])
+            .await
+            .map_err(internal_error)?
+            .and_then(|row| row.get("merged_into"));
+        if let Some(merged_into) = merged_into {
+            return Err((StatusCode::CONFLICT, format!("Participant with ID {} was merged into {} and cannot be restored", id, merged_into)));
+        }
+        // Either there is no such participant or they are not archived.
+        fetch_confirmand(&conn, id).await?;
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} is not archived", id)));
+    }
+    let confirmand = fetch_confirmand(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(confirmand.version))], Json(confirmand)))
+}
+
+// Handler for `DELETE /api/confirmands/:id/purge`
+// Permanently deletes an archived participant, with everything that cascades from the row.
+// Administrators only.
+pub async fn purge_confirmand(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    if fetch_user_access(&conn, user.id).await?.role != UserRole::Admin {
+        return Err((StatusCode::FORBIDDEN, "Only administrators can permanently delete participants".to_string()));
+    }
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+    let guardian_ids: Vec<i32> = transaction
+        .query("SELECT guardian_id FROM confirmand_guardians WHERE confirmand_id = $1", &[&id])
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    let purged = transaction
+        .execute("DELETE FROM confirmands WHERE id = $1 AND archived_at IS NOT NULL", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if purged == 0 {
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} must be archived before it can be purged", id)));
     }
+    // Their guardians who are no longer linked to anyone go with them, unless they gave a consent
+    // for another participant.
+    transaction
+        .execute(
+            "DELETE FROM guardians g WHERE g.id = ANY($1)
+             AND NOT EXISTS (SELECT 1 FROM confirmand_guardians cg WHERE cg.guardian_id = g.id)
+             AND NOT EXISTS (SELECT 1 FROM consents co WHERE co.guardian_id = g.id)",
+            &[&guardian_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+// Shared SELECT for `Catechist` rows. It finds the most recent group for each catechist
+// (DISTINCT ON by start_date) and their current workload. Callers append WHERE/ORDER BY clauses.
+const CATECHIST_SELECT_SQL: &str = "
+    WITH LatestGroup AS (
+        SELECT DISTINCT ON (catechist_id)
+            catechist_id,
+            id as latest_group_id,
+            module as latest_group_module,
+            start_date as latest_group_start_date
+        FROM confirmation_groups
+        WHERE catechist_id IS NOT NULL
+        ORDER BY catechist_id, start_date DESC
+    ),
+    Workload AS (
+        SELECT
+            cg.catechist_id,
+            COUNT(DISTINCT cg.id) as active_group_count,
+            COUNT(ccg.confirmand_id) as total_member_count
+        FROM confirmation_groups cg
+        LEFT JOIN confirmand_confirmation_groups ccg ON cg.id = ccg.confirmation_group_id
+        WHERE cg.catechist_id IS NOT NULL
+          AND (cg.end_date IS NULL OR cg.end_date >= CURRENT_DATE)
+        GROUP BY cg.catechist_id
+    )
+    SELECT 
+        c.id, c.full_name, c.currently_active, c.max_concurrent_groups, c.version,
+        lg.latest_group_id,
+        lg.latest_group_module,
+        lg.latest_group_start_date,
+        COALESCE(w.active_group_count, 0) as active_group_count,
+        COALESCE(w.total_member_count, 0) as total_member_count
+    FROM catechists c
+    LEFT JOIN LatestGroup lg ON c.id = lg.catechist_id
+    LEFT JOIN Workload w ON c.id = w.catechist_id
+";
+
 // Handler for `GET /api/catechists`
 pub async fn list_catechists(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
@@ -161,30 +749,10 @@ pub async fn list_catechists(
 ) -> Result<Json<Vec<Catechist>>, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
     
-    // This query uses a Common Table Expression (CTE) with DISTINCT ON to find the most recent
-    // group for each catechist based on the start_date.
-    let sql = "
-        WITH LatestGroup AS (
-            SELECT DISTINCT ON (catechist_id)
-                catechist_id,
-                id as latest_group_id,
-                module as latest_group_module,
-                start_date as latest_group_start_date
-            FROM confirmation_groups
-            WHERE catechist_id IS NOT NULL
-            ORDER BY catechist_id, start_date DESC
-        )
-        SELECT 
-            c.id, c.full_name, c.currently_active,
-            lg.latest_group_id,
-            lg.latest_group_module,
-            lg.latest_group_start_date
-        FROM catechists c
-        LEFT JOIN LatestGroup lg ON c.id = lg.catechist_id
-        ORDER BY c.full_name
-    ";
+    // Uses the shared CTE query to include each catechist's latest group and workload.
+    let sql = format!("{} ORDER BY c.full_name", CATECHIST_SELECT_SQL);
 
-    let rows = conn.query(sql, &[]).await.map_err(internal_error)?;
+    let rows = conn.query(&sql, &[]).await.map_err(internal_error)?;
     let catechists: Vec<Catechist> = rows.into_iter().map(Catechist::from).collect();
     Ok(Json(catechists))
 }
@@ -193,26 +761,16 @@ pub async fn get_catechist_details(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path(id): Path<i32>,
-) -> Result<Json<CatechistDetails>, (StatusCode, String)> {
+) -> Result<impl IntoResponse, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
 
     // Step 1: Get the main catechist info (this query is correct)
-    let catechist_sql = "
-        WITH LatestGroup AS (
-            SELECT DISTINCT ON (catechist_id)
-                catechist_id, id as latest_group_id, module as latest_group_module, start_date as latest_group_start_date
-            FROM confirmation_groups
-            WHERE catechist_id IS NOT NULL
-            ORDER BY catechist_id, start_date DESC
-        )
-        SELECT 
-            c.id, c.full_name, c.currently_active,
-            lg.latest_group_id, lg.latest_group_module, lg.latest_group_start_date
-        FROM catechists c
-        LEFT JOIN LatestGroup lg ON c.id = lg.catechist_id
-        WHERE c.id = $1
-    ";
-    let catechist_row = conn.query_one(catechist_sql, &[&id]).await.map_err(internal_error)?;
+    let catechist_sql = format!("{} WHERE c.id = $1", CATECHIST_SELECT_SQL);
+    let catechist_row = conn
+        .query_opt(&catechist_sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Catechist with ID {} not found", id)))?;
     let catechist = Catechist::from(catechist_row);
 
     // Step 2: Get their entire group history
@@ -222,6 +780,7 @@ pub async fn get_catechist_details(
             cg.id, 
             cg.module,
             cg.start_date,
+            cg.end_date,
             c.full_name as catechist_name
         FROM confirmation_groups cg
         LEFT JOIN catechists c ON cg.catechist_id = c.id
@@ -234,6 +793,7 @@ pub async fn get_catechist_details(
         id: row.get("id"),
         module: row.get("module"),
         start_date: row.get("start_date"),
+        end_date: row.get("end_date"),
         catechist_name: row.get("catechist_name"),
     }).collect();
 
@@ -242,23 +802,24 @@ pub async fn get_catechist_details(
         catechist,
         group_history,
     };
-    Ok(Json(details))
+    Ok(([(header::ETAG, etag::etag(details.catechist.version))], Json(details)))
 }
 
 // Handler for `POST /api/catechists`
 pub async fn create_catechist(
     user: AuthenticatedUser,
     State(state): State<AppState>,
-    Json(payload): Json<CreateCatechist>,
+    Json(mut payload): Json<CreateCatechist>,
 ) -> Result<(StatusCode, Json<Catechist>), (StatusCode, String)> {
     println!("[CREATE CATECHIST] Auth successful for user: {}", user.id);
-    let conn = state.get().await.map_err(internal_error)?;
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
 
     // Step 1: Insert the new catechist and only return its new ID.
     let insert_row = conn
         .query_one(
-            "INSERT INTO catechists (full_name, currently_active) VALUES ($1, $2) RETURNING id",
-            &[&payload.full_name, &payload.currently_active],
+            "INSERT INTO catechists (full_name, currently_active, max_concurrent_groups) VALUES ($1, $2, $3) RETURNING id",
+            &[&payload.full_name, &payload.currently_active, &payload.max_concurrent_groups],
         )
         .await
         .map_err(internal_error)?;
@@ -268,28 +829,9 @@ pub async fn create_catechist(
 
     // Step 2: Fetch the complete, newly created record using the same advanced query from `list_catechists`.
     // This guarantees the returned object has the correct shape, including the calculated fields.
-    let select_sql = "
-        WITH LatestGroup AS (
-            SELECT DISTINCT ON (catechist_id)
-                catechist_id,
-                id as latest_group_id,
-                module as latest_group_module,
-                start_date as latest_group_start_date
-            FROM confirmation_groups
-            WHERE catechist_id IS NOT NULL
-            ORDER BY catechist_id, start_date DESC
-        )
-        SELECT 
-            c.id, c.full_name, c.currently_active,
-            lg.latest_group_id,
-            lg.latest_group_module,
-            lg.latest_group_start_date
-        FROM catechists c
-        LEFT JOIN LatestGroup lg ON c.id = lg.catechist_id
-        WHERE c.id = $1
-    ";
+    let select_sql = format!("{} WHERE c.id = $1", CATECHIST_SELECT_SQL);
     
-    let new_catechist_row = conn.query_one(select_sql, &[&new_id]).await.map_err(internal_error)?;
+    let new_catechist_row = conn.query_one(&select_sql, &[&new_id]).await.map_err(internal_error)?;
     let new_catechist = Catechist::from(new_catechist_row);
     println!("[CREATE CATECHIST] Returning new catechist object: {:?}", new_catechist);
 
@@ -300,6 +842,7 @@ pub async fn create_catechist(
 pub async fn list_groups(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
+    Query(filter): Query<YearFilter>,
 ) -> Result<Json<Vec<ConfirmationGroup>>, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
 
@@ -310,13 +853,17 @@ pub async fn list_groups(
         SELECT 
             cg.id, cg.module, cg.catechist_id, cg.group_link, cg.start_date, cg.end_date,
             cg.day_of_the_week::TEXT as day_of_the_week,
-            c.full_name as catechist_name
+            cg.start_time, cg.end_time, cg.room_id, cg.catechetical_year_id, cg.version,
+            c.full_name as catechist_name,
+            r.name as room_name
         FROM confirmation_groups cg
         LEFT JOIN catechists c ON cg.catechist_id = c.id
+        LEFT JOIN rooms r ON cg.room_id = r.id
+        WHERE $1::INT IS NULL OR cg.catechetical_year_id = $1
         ORDER BY cg.start_date DESC
     ";
 
-    let rows = conn.query(sql, &[]).await.map_err(internal_error)?;
+    let rows = conn.query(sql, &[&filter.year]).await.map_err(internal_error)?;
 
     let groups: Vec<ConfirmationGroup> = rows.into_iter().map(ConfirmationGroup::from).collect();
     Ok(Json(groups))
@@ -326,19 +873,40 @@ pub async fn list_groups(
 pub async fn create_group(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
-    Json(payload): Json<CreateConfirmationGroup>,
+    Json(mut payload): Json<CreateConfirmationGroup>,
 ) -> Result<(StatusCode, Json<ConfirmationGroup>), (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+
+    let transaction = conn.transaction().await.map_err(internal_error)?;
 
+    // Refuse to double-book a room. Use `POST /api/rooms/check` to preview conflicts first.
+    if let Some(room_id) = payload.room_id {
+        let check = RoomBookingCheck {
+            room_id,
+            day_of_the_week: payload.day_of_the_week.clone(),
+            start_time: payload.start_time,
+            end_time: payload.end_time,
+            start_date: payload.start_date,
+            end_date: payload.end_date,
+            exclude_group_id: None,
+        };
+        ensure_room_is_free(&transaction, &check).await?;
+    }
+
+    // Without an explicit year, attach the group to the year its start_date falls in (if any).
     let insert_sql = "
         INSERT INTO confirmation_groups 
-            (module, catechist_id, day_of_the_week, group_link, start_date, end_date)
-        VALUES ($1, $2, CAST($3 AS VARCHAR)::day_of_week_enum, $4, $5, $6)
+            (module, catechist_id, day_of_the_week, group_link, start_date, end_date, start_time, end_time, room_id,
+             catechetical_year_id)
+        VALUES ($1, $2, CAST($3 AS VARCHAR)::day_of_week_enum, $4, $5, $6, $7, $8, $9,
+                COALESCE($10, (SELECT id FROM catechetical_years WHERE $5 BETWEEN start_date AND end_date
+                               ORDER BY start_date DESC LIMIT 1)))
         RETURNING id
     ";
 
     // First, we insert the new group and get its ID back.
-    let row = conn
+    let row = transaction
         .query_one(
             insert_sql,
             &[
@@ -348,6 +916,10 @@ pub async fn create_group(
                 &payload.group_link,
                 &payload.start_date,
                 &payload.end_date,
+                &payload.start_time,
+                &payload.end_time,
+                &payload.room_id,
+                &payload.catechetical_year_id,
             ],
         )
         .await
@@ -360,13 +932,17 @@ pub async fn create_group(
         SELECT 
             cg.id, cg.module, cg.catechist_id, cg.group_link, cg.start_date, cg.end_date,
             cg.day_of_the_week::TEXT as day_of_the_week,
-            c.full_name as catechist_name
+            cg.start_time, cg.end_time, cg.room_id, cg.catechetical_year_id, cg.version,
+            c.full_name as catechist_name,
+            r.name as room_name
         FROM confirmation_groups cg
         LEFT JOIN catechists c ON cg.catechist_id = c.id
+        LEFT JOIN rooms r ON cg.room_id = r.id
         WHERE cg.id = $1
     ";
 
-    let new_group_row = conn.query_one(select_sql, &[&new_id]).await.map_err(internal_error)?;
+    let new_group_row = transaction.query_one(select_sql, &[&new_id]).await.map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
 
     let new_group = ConfirmationGroup::from(new_group_row);
     Ok((StatusCode::CREATED, Json(new_group)))
@@ -376,32 +952,63 @@ pub async fn get_group_details(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path(id): Path<i32>,
-) -> Result<Json<ConfirmationGroupDetails>, (StatusCode, String)> {
+) -> Result<impl IntoResponse, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
+    let details = fetch_group_details(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(details.version))], Json(details)))
+}
 
+async fn fetch_group_details(conn: &Client, id: i32) -> Result<ConfirmationGroupDetails, (StatusCode, String)> {
     // Step 1: Fetch the main group details (this part is correct)
     let group_sql = "
         SELECT 
-            cg.id, cg.module, cg.start_date,
+            cg.id, cg.module, cg.start_date, cg.version,
             cg.day_of_the_week::TEXT as day_of_the_week,
-            c.full_name as catechist_name
+            cg.start_time, cg.end_time, cg.room_id,
+            c.full_name as catechist_name,
+            r.name as room_name
         FROM confirmation_groups cg
         LEFT JOIN catechists c ON cg.catechist_id = c.id
+        LEFT JOIN rooms r ON cg.room_id = r.id
         WHERE cg.id = $1
     ";
-    let group_row = conn.query_one(group_sql, &[&id]).await.map_err(internal_error)?;
+    let group_row = conn
+        .query_opt(group_sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Group with ID {} not found", id)))?;
+
+    // Sessions that were moved to a different room than the group's usual one
+    let session_rooms_sql = "
+        SELECT gsr.session_date, gsr.room_id, r.name as room_name
+        FROM group_session_rooms gsr
+        INNER JOIN rooms r ON gsr.room_id = r.id
+        WHERE gsr.confirmation_group_id = $1
+        ORDER BY gsr.session_date
+    ";
+    let session_room_rows = conn.query(session_rooms_sql, &[&id]).await.map_err(internal_error)?;
+    let session_rooms: Vec<SessionRoom> = session_room_rows.into_iter().map(|row| SessionRoom {
+        session_date: row.get("session_date"),
+        room_id: row.get("room_id"),
+        room_name: row.get("room_name"),
+    }).collect();
 
     // Step 2: Fetch the list of members in this group - THE QUERY IS UPDATED
     let members_sql = "
         SELECT 
             c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-            c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+            c.birth_date, c.address, c.version, c.archived_at,
+            confirmand_guardian_name(c.id, 'Father') as father_name,
+            confirmand_guardian_name(c.id, 'Mother') as mother_name,
+            sc.baptism_church, sc.communion_church,
             cg.id as current_group_id,
-            cg.module as current_group_module
+            cg.module as current_group_module,
+            cg.start_date as current_group_start_date
         FROM confirmands c
+        LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
         INNER JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
         LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
-        WHERE ccg.confirmation_group_id = $1
+        WHERE ccg.confirmation_group_id = $1 AND c.archived_at IS NULL
         ORDER BY c.full_name
     ";
     let member_rows = conn.query(members_sql, &[&id]).await.map_err(internal_error)?;
@@ -414,10 +1021,16 @@ pub async fn get_group_details(
         catechist_name: group_row.get("catechist_name"),
         day_of_the_week: group_row.get("day_of_the_week"),
         start_date: group_row.get("start_date"),
+        start_time: group_row.get("start_time"),
+        end_time: group_row.get("end_time"),
+        room_id: group_row.get("room_id"),
+        room_name: group_row.get("room_name"),
+        version: group_row.get("version"),
+        session_rooms,
         members,
     };
 
-    Ok(Json(group_details))
+    Ok(group_details)
 }
 
 // --- NEW --- Handler for `POST /api/groups/:id/participants`
@@ -427,7 +1040,18 @@ pub async fn add_participant_to_group(
     Path(group_id): Path<i32>,
     Json(payload): Json<AddParticipantToGroup>,
 ) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, String)> { // Return type is now Json<Value>
-    let conn = state.get().await.map_err(internal_error)?;
+    let conn = connect_as(&state, &user).await?;
+
+    // Archived participants have to be restored before they can join a group.
+    let archived: bool = conn
+        .query_opt("SELECT archived_at IS NOT NULL as archived FROM confirmands WHERE id = $1", &[&payload.confirmand_id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", payload.confirmand_id)))?
+        .get("archived");
+    if archived {
+        return Err((StatusCode::CONFLICT, format!("Participant with ID {} is archived", payload.confirmand_id)));
+    }
 
     let sql = "
         INSERT INTO confirmand_confirmation_groups (confirmand_id, confirmation_group_id)
@@ -450,15 +1074,20 @@ pub async fn remove_participant_from_group(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path((group_id, confirmand_id)): Path<(i32, i32)>, // Axum can extract multiple path params into a tuple
+    headers: HeaderMap,
 ) -> Result<StatusCode, (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmation_groups", group_id, expected_version, format!("Group with ID {} not found", group_id)).await?;
 
     let sql = "
         DELETE FROM confirmand_confirmation_groups
         WHERE confirmand_id = $1 AND confirmation_group_id = $2
     ";
 
-    conn.execute(sql, &[&confirmand_id, &group_id]).await.map_err(internal_error)?;
+    transaction.execute(sql, &[&confirmand_id, &group_id]).await.map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
 
     // DELETE is idempotent, so we don't need to check if a row was actually deleted.
     // We just ensure the state is what the user wants (the link doesn't exist).
@@ -472,7 +1101,7 @@ pub async fn list_all_sacraments(
 ) -> Result<Json<Vec<Sacrament>>, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
     let rows = conn
-        .query("SELECT id, name FROM sacraments ORDER BY id", &[])
+        .query("SELECT id, name, code FROM sacraments ORDER BY id", &[])
         .await
         .map_err(internal_error)?;
     let sacraments: Vec<Sacrament> = rows.into_iter().map(Sacrament::from).collect();
@@ -485,35 +1114,43 @@ pub async fn get_participant_details(
     user: AuthenticatedUser,
     State(state): State<AppState>,
     Path(id): Path<i32>,
-) -> Result<Json<ConfirmandDetails>, (StatusCode, String)> {
+) -> Result<impl IntoResponse, (StatusCode, String)> {
     let conn = state.get().await.map_err(internal_error)?;
+    let details = fetch_participant_details(&conn, id).await?;
+    Ok(([(header::ETAG, etag::etag(details.confirmand.version))], Json(details)))
+}
 
-    // Step 1: Get the main participant info. THIS QUERY IS NOW CORRECTED.
+// Loads everything shown on the participant page. Also used by the eligibility check.
+async fn fetch_participant_details(conn: &Client, id: i32) -> Result<ConfirmandDetails, (StatusCode, String)> {
+    // Step 1: Get the main participant info, with their most recent group as the current one.
     let confirmand_sql = "
-        SELECT 
+        SELECT DISTINCT ON (c.id)
             c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-            c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+            c.birth_date, c.address, c.version, c.archived_at,
+            confirmand_guardian_name(c.id, 'Father') as father_name,
+            confirmand_guardian_name(c.id, 'Mother') as mother_name,
+            sc.baptism_church, sc.communion_church,
             cg.id as current_group_id,
             cg.module as current_group_module,
             cg.start_date as current_group_start_date -- This was the missing column
         FROM confirmands c
+        LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
         LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
         LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
         WHERE c.id = $1
+        ORDER BY c.id, cg.start_date DESC
     ";
-    let confirmand_row = conn.query_one(confirmand_sql, &[&id]).await.map_err(internal_error)?;
+    let confirmand_row = conn
+        .query_opt(confirmand_sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)))?;
     let confirmand = Confirmand::from(confirmand_row);
 
-    // Step 2: Get their completed sacraments (this was already correct)
-    let sacraments_sql = "
-        SELECT s.id, s.name 
-        FROM sacraments s
-        INNER JOIN confirmand_sacraments cs ON s.id = cs.sacrament_id
-        WHERE cs.confirmand_id = $1
-        ORDER BY s.id
-    ";
-    let sacrament_rows = conn.query(sacraments_sql, &[&id]).await.map_err(internal_error)?;
-    let sacraments: Vec<Sacrament> = sacrament_rows.into_iter().map(Sacrament::from).collect();
+    // Step 2: Get their completed sacraments, with the parish register details
+    let sacraments_sql = format!("{} WHERE cs.confirmand_id = $1 ORDER BY s.id", SACRAMENT_RECORD_SELECT_SQL);
+    let sacrament_rows = conn.query(&sacraments_sql, &[&id]).await.map_err(internal_error)?;
+    let sacraments: Vec<SacramentRecord> = sacrament_rows.into_iter().map(SacramentRecord::from).collect();
 
     // Step 3: Get their entire group history (this was already correct)
     let history_sql = "
@@ -521,6 +1158,7 @@ pub async fn get_participant_details(
             cg.id, 
             cg.module,
             cg.start_date,
+            cg.end_date,
             c.full_name as catechist_name
         FROM confirmation_groups cg
         INNER JOIN confirmand_confirmation_groups ccg ON cg.id = ccg.confirmation_group_id
@@ -533,32 +1171,136 @@ pub async fn get_participant_details(
         id: row.get("id"),
         module: row.get("module"),
         start_date: row.get("start_date"),
+        end_date: row.get("end_date"),
         catechist_name: row.get("catechist_name"),
     }).collect();
 
-    // Step 4: Combine into the final response model (this was already correct)
-    let details = ConfirmandDetails {
+    // Step 4: Get their sponsor, if one has been recorded
+    let sponsor_sql = format!("{} WHERE confirmand_id = $1", SPONSOR_SELECT_SQL);
+    let sponsor = conn.query_opt(&sponsor_sql, &[&id]).await.map_err(internal_error)?.map(Sponsor::from);
+
+    // Step 5: Get their parents and guardians, primary contact first
+    let guardians_sql = format!("{} WHERE cg.confirmand_id = $1 ORDER BY cg.is_primary_contact DESC, g.full_name", GUARDIAN_SELECT_SQL);
+    let guardian_rows = conn.query(&guardians_sql, &[&id]).await.map_err(internal_error)?;
+    let guardians: Vec<Guardian> = guardian_rows.into_iter().map(Guardian::from).collect();
+
+    // Step 6: Get their household, if they belong to one
+    let household_sql = format!(
+        "{} WHERE h.id = (SELECT household_id FROM confirmands WHERE id = $1) GROUP BY h.id",
+        HOUSEHOLD_SELECT_SQL
+    );
+    let household = conn.query_opt(&household_sql, &[&id]).await.map_err(internal_error)?.map(Household::from);
+
+    // Step 7: Combine into the final response model (this was already correct)
+    Ok(ConfirmandDetails {
         confirmand,
         sacraments,
         group_history,
-    };
-    Ok(Json(details))
+        sponsor,
+        guardians,
+        household,
+    })
 }
 
+const SACRAMENT_RECORD_SELECT_SQL: &str = "
+    SELECT
+        cs.confirmand_id, s.id, s.name, s.code,
+        cs.received_date, cs.church, cs.parish, cs.officiant,
+        cs.register_book, cs.register_page, cs.register_entry
+    FROM sacraments s
+    INNER JOIN confirmand_sacraments cs ON s.id = cs.sacrament_id
+";
+
 // Handler for `POST /api/confirmands/:id/sacraments`
+// Adding a sacrament the participant already has leaves the existing record untouched (200 instead of 201).
 pub async fn add_sacrament_to_participant(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path(confirmand_id): Path<i32>,
     Json(payload): Json<UpdateParticipantSacrament>,
-) -> Result<StatusCode, (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+) -> Result<(StatusCode, Json<SacramentRecord>), (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let details = &payload.details;
+    let sql = "
+        INSERT INTO confirmand_sacraments (
+            confirmand_id, sacrament_id, received_date, church, parish, officiant,
+            register_book, register_page, register_entry
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT DO NOTHING
+    ";
+    let inserted = conn
+        .execute(
+            sql,
+            &[
+                &confirmand_id,
+                &payload.sacrament_id,
+                &details.received_date,
+                &details.church,
+                &details.parish,
+                &details.officiant,
+                &details.register_book,
+                &details.register_page,
+                &details.register_entry,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let select_sql = format!("{} WHERE cs.confirmand_id = $1 AND cs.sacrament_id = $2", SACRAMENT_RECORD_SELECT_SQL);
+    let row = conn.query_one(&select_sql, &[&confirmand_id, &payload.sacrament_id]).await.map_err(internal_error)?;
+    let status = if inserted > 0 { StatusCode::CREATED } else { StatusCode::OK };
+    Ok((status, Json(SacramentRecord::from(row))))
+}
+
+// Handler for `PUT /api/confirmands/:confirmandId/sacraments/:sacramentId`
+// Replaces the register details of a sacrament the participant has already received.
+pub async fn update_participant_sacrament(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((confirmand_id, sacrament_id)): Path<(i32, i16)>,
+    headers: HeaderMap,
+    Json(payload): Json<SacramentRecordDetails>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let not_found = format!("Participant with ID {} not found", confirmand_id);
+    lock_version(&transaction, "confirmands", confirmand_id, expected_version, not_found).await?;
     let sql = "
-        INSERT INTO confirmand_sacraments (confirmand_id, sacrament_id)
-        VALUES ($1, $2) ON CONFLICT DO NOTHING
+        UPDATE confirmand_sacraments
+        SET received_date = $3, church = $4, parish = $5, officiant = $6,
+            register_book = $7, register_page = $8, register_entry = $9
+        WHERE confirmand_id = $1 AND sacrament_id = $2
     ";
-    conn.execute(sql, &[&confirmand_id, &payload.sacrament_id]).await.map_err(internal_error)?;
-    Ok(StatusCode::CREATED)
+    let result = transaction
+        .execute(
+            sql,
+            &[
+                &confirmand_id,
+                &sacrament_id,
+                &payload.received_date,
+                &payload.church,
+                &payload.parish,
+                &payload.officiant,
+                &payload.register_book,
+                &payload.register_page,
+                &payload.register_entry,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+    if result == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Participant {} has no record of sacrament {}", confirmand_id, sacrament_id),
+        ));
+    }
+
+    let select_sql = format!("{} WHERE cs.confirmand_id = $1 AND cs.sacrament_id = $2", SACRAMENT_RECORD_SELECT_SQL);
+    let row = transaction.query_one(&select_sql, &[&confirmand_id, &sacrament_id]).await.map_err(internal_error)?;
+    let version = current_version(&transaction, "confirmands", confirmand_id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(([(header::ETAG, etag::etag(version))], Json(SacramentRecord::from(row))))
 }
 
 // Handler for `DELETE /api/confirmands/:confirmandId/sacraments/:sacramentId`
@@ -566,11 +1308,18 @@ pub async fn remove_sacrament_from_participant(
     user: AuthenticatedUser,  // Ensure only admins can access this endpoint
     State(state): State<AppState>,
     Path((confirmand_id, sacrament_id)): Path<(i32, i16)>,
-) -> Result<StatusCode, (StatusCode, String)> {
-    let conn = state.get().await.map_err(internal_error)?;
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let not_found = format!("Participant with ID {} not found", confirmand_id);
+    lock_version(&transaction, "confirmands", confirmand_id, expected_version, not_found).await?;
     let sql = "DELETE FROM confirmand_sacraments WHERE confirmand_id = $1 AND sacrament_id = $2";
-    conn.execute(sql, &[&confirmand_id, &sacrament_id]).await.map_err(internal_error)?;
-    Ok(StatusCode::NO_CONTENT)
+    transaction.execute(sql, &[&confirmand_id, &sacrament_id]).await.map_err(internal_error)?;
+    let version = current_version(&transaction, "confirmands", confirmand_id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok((StatusCode::NO_CONTENT, [(header::ETAG, etag::etag(version))]))
 }
 
 // ===================================================================
@@ -590,8 +1339,11 @@ pub async fn import_confirmands_from_csv(
 
     let mut imported_emails = Vec::new();
     let mut skipped_count = 0;
+    // The spreadsheet carries no guardian consent, so minors are left to be registered by hand.
+    let mut minors_skipped = 0;
+    let today = Utc::now().date_naive();
 
-    let conn = state.get().await.map_err(internal_error)?;
+    let conn = connect_as(&state, &user).await?;
 
     for result in reader.records() {
         let record = match result {
@@ -621,10 +1373,21 @@ pub async fn import_confirmands_from_csv(
             _ => MaritalStatus::Single,
         };
         
-        let new_participant = CreateConfirmand {
+        let mut new_participant = CreateConfirmand {
             full_name, birth_date, address, phone_number, email, marital_status,
             father_name: None, mother_name: None, baptism_church: None, communion_church: None,
+            guardian_consent: None,
         };
+        // Rows that would be rejected by the form are skipped rather than imported half-valid.
+        if new_participant.validate(today).is_err() {
+            skipped_count += 1;
+            continue;
+        }
+        if assignment::age_on(new_participant.birth_date, today) < ADULT_AGE {
+            skipped_count += 1;
+            minors_skipped += 1;
+            continue;
+        }
 
         // This query now returns the email of the inserted row.
         // It will only return a row if the INSERT was successful (not a conflict).
@@ -654,11 +1417,15 @@ pub async fn import_confirmands_from_csv(
         let select_sql = "
             SELECT 
                 c.id, c.full_name, c.email, c.phone_number, c.creation_date, c.marital_status::TEXT as marital_status,
-                c.birth_date, c.address, c.father_name, c.mother_name, c.baptism_church, c.communion_church,
+                c.birth_date, c.address, c.version, c.archived_at,
+                confirmand_guardian_name(c.id, 'Father') as father_name,
+                confirmand_guardian_name(c.id, 'Mother') as mother_name,
+                sc.baptism_church, sc.communion_church,
                 cg.id as current_group_id,
                 cg.module as current_group_module,
                 cg.start_date as current_group_start_date
             FROM confirmands c
+            LEFT JOIN confirmand_sacrament_churches sc ON c.id = sc.confirmand_id
             LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
             LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
             WHERE c.email = ANY($1)
@@ -673,6 +1440,7 @@ pub async fn import_confirmands_from_csv(
         "status": "success",
         "new_participants_imported": new_participants_count,
         "rows_skipped": skipped_count,
+        "minors_skipped": minors_skipped,
         "imported_records": imported_confirmands // Send the full records back
     })))
 }
@@ -680,15 +1448,39 @@ pub async fn import_confirmands_from_csv(
 pub async fn get_dashboard_stats(
     user: AuthenticatedUser,
     State(state): State<AppState>,
+    Query(filter): Query<YearFilter>,
 ) -> Result<Json<DashboardStats>, (StatusCode, String)> {
     // MODIFIED: The connection is now mutable
     let mut conn = state.get().await.map_err(internal_error)?;
 
     let transaction = conn.transaction().await.map_err(internal_error)?;
 
-    let p_count_row = transaction.query_one("SELECT COUNT(*) FROM confirmands", &[]).await.map_err(internal_error)?;
-    let c_count_row = transaction.query_one("SELECT COUNT(*) FROM catechists WHERE currently_active = TRUE", &[]).await.map_err(internal_error)?;
-    let g_count_row = transaction.query_one("SELECT COUNT(*) FROM confirmation_groups WHERE end_date IS NULL", &[]).await.map_err(internal_error)?;
+    // Without a year we keep the all-time counts; with one, everything is scoped to that year's groups.
+    let (p_count_row, c_count_row, g_count_row) = match filter.year {
+        None => (
+            transaction.query_one("SELECT COUNT(*) FROM confirmands WHERE archived_at IS NULL", &[]).await.map_err(internal_error)?,
+            transaction.query_one("SELECT COUNT(*) FROM catechists WHERE currently_active = TRUE", &[]).await.map_err(internal_error)?,
+            transaction.query_one("SELECT COUNT(*) FROM confirmation_groups WHERE end_date IS NULL", &[]).await.map_err(internal_error)?,
+        ),
+        Some(year) => (
+            transaction.query_one(
+                "SELECT COUNT(DISTINCT ccg.confirmand_id)
+                 FROM confirmand_confirmation_groups ccg
+                 INNER JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+                 INNER JOIN confirmands c ON ccg.confirmand_id = c.id
+                 WHERE cg.catechetical_year_id = $1 AND c.archived_at IS NULL",
+                &[&year],
+            ).await.map_err(internal_error)?,
+            transaction.query_one(
+                "SELECT COUNT(DISTINCT catechist_id) FROM confirmation_groups WHERE catechetical_year_id = $1",
+                &[&year],
+            ).await.map_err(internal_error)?,
+            transaction.query_one(
+                "SELECT COUNT(*) FROM confirmation_groups WHERE catechetical_year_id = $1 AND end_date IS NULL",
+                &[&year],
+            ).await.map_err(internal_error)?,
+        ),
+    };
     
     transaction.commit().await.map_err(internal_error)?;
 
@@ -701,6 +1493,3312 @@ pub async fn get_dashboard_stats(
     Ok(Json(stats))
 }
 
+// ===================================================================
+// Calendar Feed Handlers
+// ===================================================================
+
+// Handler for `POST /api/groups/:id/calendar-feeds`
+pub async fn create_group_calendar_feed(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(group_id): Path<i32>,
+) -> Result<(StatusCode, Json<CalendarFeed>), (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+
+    let exists = conn.query_opt("SELECT id FROM confirmation_groups WHERE id = $1", &[&group_id])
+        .await.map_err(internal_error)?;
+    if exists.is_none() {
+        return Err((StatusCode::NOT_FOUND, format!("Group with ID {} not found", group_id)));
+    }
+
+    let sql = "
+        INSERT INTO calendar_feeds (token, confirmation_group_id)
+        VALUES ($1, $2)
+        RETURNING id, token, confirmation_group_id, catechist_id, created_at, revoked_at
+    ";
+    let row = conn.query_one(sql, &[&Uuid::new_v4(), &group_id]).await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(CalendarFeed::from(row))))
+}
+
+// Handler for `GET /api/groups/:id/calendar-feeds`
+pub async fn list_group_calendar_feeds(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(group_id): Path<i32>,
+) -> Result<Json<Vec<CalendarFeed>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = "
+        SELECT id, token, confirmation_group_id, catechist_id, created_at, revoked_at
+        FROM calendar_feeds
+        WHERE confirmation_group_id = $1
+        ORDER BY created_at DESC
+    ";
+    let rows = conn.query(sql, &[&group_id]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(CalendarFeed::from).collect()))
+}
+
+// Handler for `POST /api/catechists/:id/calendar-feeds`
+pub async fn create_catechist_calendar_feed(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(catechist_id): Path<i32>,
+) -> Result<(StatusCode, Json<CalendarFeed>), (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+
+    let exists = conn.query_opt("SELECT id FROM catechists WHERE id = $1", &[&catechist_id])
+        .await.map_err(internal_error)?;
+    if exists.is_none() {
+        return Err((StatusCode::NOT_FOUND, format!("Catechist with ID {} not found", catechist_id)));
+    }
+
+    let sql = "
+        INSERT INTO calendar_feeds (token, catechist_id)
+        VALUES ($1, $2)
+        RETURNING id, token, confirmation_group_id, catechist_id, created_at, revoked_at
+    ";
+    let row = conn.query_one(sql, &[&Uuid::new_v4(), &catechist_id]).await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(CalendarFeed::from(row))))
+}
+
+// Handler for `GET /api/catechists/:id/calendar-feeds`
+pub async fn list_catechist_calendar_feeds(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(catechist_id): Path<i32>,
+) -> Result<Json<Vec<CalendarFeed>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = "
+        SELECT id, token, confirmation_group_id, catechist_id, created_at, revoked_at
+        FROM calendar_feeds
+        WHERE catechist_id = $1
+        ORDER BY created_at DESC
+    ";
+    let rows = conn.query(sql, &[&catechist_id]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(CalendarFeed::from).collect()))
+}
+
+// Handler for `DELETE /api/calendar-feeds/:id`
+// Revoking is permanent: the token stops resolving and a new feed has to be created.
+pub async fn revoke_calendar_feed(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let result = conn
+        .execute("UPDATE calendar_feeds SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if result == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Active calendar feed with ID {} not found", id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `GET /api/calendar/:token.ics`
+// This route is intentionally NOT protected by `AuthenticatedUser`: calendar apps cannot log in,
+// so the unguessable token in the URL is the credential.
+pub async fn get_calendar_feed(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let not_found = || (StatusCode::NOT_FOUND, "Calendar feed not found".to_string());
+    let token = file.strip_suffix(".ics").unwrap_or(&file);
+    let token = Uuid::parse_str(token).map_err(|_| not_found())?;
+
+    let conn = state.get().await.map_err(internal_error)?;
+    let feed_row = conn
+        .query_opt(
+            "SELECT confirmation_group_id, catechist_id FROM calendar_feeds WHERE token = $1 AND revoked_at IS NULL",
+            &[&token],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(not_found)?;
+    let group_id: Option<i32> = feed_row.get("confirmation_group_id");
+    let catechist_id: Option<i32> = feed_row.get("catechist_id");
+
+    let groups_sql = "
+        SELECT
+            cg.id, cg.module, cg.group_link, cg.start_date, cg.end_date,
+            cg.day_of_the_week::TEXT as day_of_the_week,
+            cg.start_time, cg.end_time,
+            c.full_name as catechist_name,
+            r.name as room_name,
+            l.name as location_name,
+            l.address as location_address
+        FROM confirmation_groups cg
+        LEFT JOIN catechists c ON cg.catechist_id = c.id
+        LEFT JOIN rooms r ON cg.room_id = r.id
+        LEFT JOIN locations l ON r.location_id = l.id
+        WHERE ($1::INT IS NOT NULL AND cg.id = $1) OR ($2::INT IS NOT NULL AND cg.catechist_id = $2)
+        ORDER BY cg.start_date
+    ";
+    let rows = conn.query(groups_sql, &[&group_id, &catechist_id]).await.map_err(internal_error)?;
+
+    let calendar_name = match catechist_id {
+        Some(id) => {
+            let row = conn.query_opt("SELECT full_name FROM catechists WHERE id = $1", &[&id])
+                .await.map_err(internal_error)?;
+            let name: String = row.map(|r| r.get(0)).unwrap_or_default();
+            format!("Confirmation groups - {}", name)
+        }
+        None => rows
+            .first()
+            .map(|r| format!("Confirmation group - Module {}", r.get::<_, i16>("module")))
+            .unwrap_or_else(|| "Confirmation group".to_string()),
+    };
+
+    let holidays = fetch_parish_holidays(&conn).await?;
+    let events: Vec<WeeklyEvent> = rows.iter().filter_map(|row| group_weekly_event(row, &holidays)).collect();
+    let body = ical::render_calendar(&calendar_name, &events);
+
+    Ok(([(header::CONTENT_TYPE, "text/calendar; charset=utf-8")], body))
+}
+
+// Builds the recurring calendar event for a group row (as selected in `get_calendar_feed`).
+// Sessions cancelled by a parish holiday are excluded from the recurrence.
+fn group_weekly_event(row: &Row, holidays: &[ParishHoliday]) -> Option<WeeklyEvent> {
+    let id: i32 = row.get("id");
+    let module: i16 = row.get("module");
+    let day = DayOfTheWeek::from_str(&row.get::<_, String>("day_of_the_week")).ok()?;
+    let group_link: Option<String> = row.get("group_link");
+    let catechist_name: Option<String> = row.get("catechist_name");
+    let room_name: Option<String> = row.get("room_name");
+    let location_name: Option<String> = row.get("location_name");
+    let location_address: Option<String> = row.get("location_address");
+    let start_date: NaiveDate = row.get("start_date");
+    let end_date: Option<NaiveDate> = row.get("end_date");
+    let plan = schedule::plan_sessions(id, day.to_weekday(), start_date, end_date, Utc::now().date_naive(), holidays);
+
+    Some(WeeklyEvent {
+        uid: format!("group-{}@nsp-crisma", id),
+        summary: format!("Confirmation - Module {}", module),
+        description: catechist_name.map(|name| format!("Catechist: {}", name)),
+        // Prefer the physical room; online groups only have their meeting link.
+        location: match (room_name, location_name) {
+            (Some(room), Some(location)) => Some(
+                [Some(room), Some(location), location_address].into_iter().flatten().collect::<Vec<_>>().join(", "),
+            ),
+            _ => group_link.clone(),
+        },
+        url: group_link,
+        weekday: day.to_weekday(),
+        start_date,
+        end_date,
+        start_time: row.get("start_time"),
+        end_time: row.get("end_time"),
+        excluded_dates: plan.skipped.iter().map(|s| s.date).collect(),
+    })
+}
+
+// ===================================================================
+// Parish Holiday & Session Schedule Handlers
+// ===================================================================
+
+async fn fetch_parish_holidays(conn: &Client) -> Result<Vec<ParishHoliday>, (StatusCode, String)> {
+    let sql = "
+        SELECT id, name, kind::TEXT as kind, start_date, end_date, easter_offset_start, easter_offset_end
+        FROM parish_holidays
+        ORDER BY id
+    ";
+    let rows = conn.query(sql, &[]).await.map_err(internal_error)?;
+    Ok(rows.into_iter().map(ParishHoliday::from).collect())
+}
+
+// Handler for `GET /api/holidays`
+pub async fn list_parish_holidays(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ParishHoliday>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_parish_holidays(&conn).await?))
+}
+
+// Handler for `POST /api/holidays`
+pub async fn create_parish_holiday(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateParishHoliday>,
+) -> Result<(StatusCode, Json<ParishHoliday>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+
+    let sql = "
+        INSERT INTO parish_holidays (name, kind, start_date, end_date, easter_offset_start, easter_offset_end)
+        VALUES ($1, CAST($2 AS VARCHAR)::holiday_kind_enum, $3, $4, $5, $6)
+        RETURNING id, name, kind::TEXT as kind, start_date, end_date, easter_offset_start, easter_offset_end
+    ";
+    let row = conn
+        .query_one(
+            sql,
+            &[
+                &payload.name.trim(),
+                &payload.kind.to_string(),
+                &payload.start_date,
+                &payload.end_date,
+                &payload.easter_offset_start,
+                &payload.easter_offset_end,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(ParishHoliday::from(row))))
+}
+
+// Handler for `DELETE /api/holidays/:id`
+pub async fn delete_parish_holiday(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let result = conn
+        .execute("DELETE FROM parish_holidays WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if result == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Holiday with ID {} not found", id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `POST /api/holidays/preview`
+// Lists the upcoming sessions that would be cancelled if the given holiday were added.
+// Nothing is saved.
+pub async fn preview_parish_holiday(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateParishHoliday>,
+) -> Result<Json<Vec<AffectedSession>>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = state.get().await.map_err(internal_error)?;
+
+    let holidays = fetch_parish_holidays(&conn).await?;
+    let candidate = ParishHoliday {
+        id: 0,
+        name: payload.name,
+        kind: payload.kind,
+        start_date: payload.start_date,
+        end_date: payload.end_date,
+        easter_offset_start: payload.easter_offset_start,
+        easter_offset_end: payload.easter_offset_end,
+    };
+
+    let today = Utc::now().date_naive();
+    let groups_sql = "
+        SELECT
+            cg.id, cg.module, cg.start_date, cg.end_date,
+            cg.day_of_the_week::TEXT as day_of_the_week,
+            c.full_name as catechist_name
+        FROM confirmation_groups cg
+        LEFT JOIN catechists c ON cg.catechist_id = c.id
+        WHERE cg.end_date IS NULL OR cg.end_date >= $1
+        ORDER BY cg.start_date, cg.id
+    ";
+    let rows = conn.query(groups_sql, &[&today]).await.map_err(internal_error)?;
+
+    let mut affected = Vec::new();
+    for row in rows {
+        let day: String = row.get("day_of_the_week");
+        let Ok(day) = DayOfTheWeek::from_str(&day) else { continue };
+        let group_id: i32 = row.get("id");
+        let plan = schedule::plan_sessions(group_id, day.to_weekday(), row.get("start_date"), row.get("end_date"), today, &holidays);
+        for date in plan.sessions.into_iter().filter(|d| *d >= today) {
+            if schedule::holiday_covers(&candidate, date) {
+                affected.push(AffectedSession {
+                    group_id,
+                    module: row.get("module"),
+                    catechist_name: row.get("catechist_name"),
+                    date,
+                });
+            }
+        }
+    }
+    Ok(Json(affected))
+}
+
+// Handler for `GET /api/groups/:id/sessions`
+pub async fn get_group_sessions(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<GroupSchedule>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_group_schedule(&conn, id).await?))
+}
+
+async fn fetch_group_schedule(conn: &Client, id: i32) -> Result<GroupSchedule, (StatusCode, String)> {
+    let row = conn
+        .query_opt(
+            "SELECT start_date, end_date, day_of_the_week::TEXT as day_of_the_week FROM confirmation_groups WHERE id = $1",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Group with ID {} not found", id)))?;
+
+    let day: String = row.get("day_of_the_week");
+    let day = DayOfTheWeek::from_str(&day).map_err(internal_error)?;
+    let holidays = fetch_parish_holidays(conn).await?;
+
+    Ok(schedule::plan_sessions(id, day.to_weekday(), row.get("start_date"), row.get("end_date"), Utc::now().date_naive(), &holidays))
+}
+
+// ===================================================================
+// Location, Room & Room Booking Handlers
+// ===================================================================
+
+// Returns every booking of `check.room_id` that overlaps the proposed weekday, time and date range.
+// Missing times are treated as "the whole day", so they always clash with the same weekday.
+async fn find_room_conflicts(
+    conn: &impl deadpool_postgres::GenericClient,
+    check: &RoomBookingCheck,
+) -> Result<Vec<RoomConflict>, (StatusCode, String)> {
+    // When checking a single session, a group that moved *that* session to another room is not a clash.
+    let single_session = check.end_date == Some(check.start_date);
+
+    let groups_sql = "
+        SELECT
+            cg.id, cg.module, cg.day_of_the_week::TEXT as day_of_the_week,
+            cg.start_time, cg.end_time, cg.start_date, cg.end_date
+        FROM confirmation_groups cg
+        WHERE cg.room_id = $1
+          AND cg.day_of_the_week = CAST($2 AS VARCHAR)::day_of_week_enum
+          AND ($3::INT IS NULL OR cg.id <> $3)
+          AND cg.start_date <= COALESCE($5::DATE, 'infinity'::DATE)
+          AND COALESCE(cg.end_date, 'infinity'::DATE) >= $4
+          AND ($6::TIME IS NULL OR $7::TIME IS NULL OR cg.start_time IS NULL OR cg.end_time IS NULL
+               OR (cg.start_time < $7 AND $6 < cg.end_time))
+          AND NOT ($8 AND EXISTS (
+              SELECT 1 FROM group_session_rooms gsr
+              WHERE gsr.confirmation_group_id = cg.id AND gsr.session_date = $4 AND gsr.room_id <> $1
+          ))
+        ORDER BY cg.start_date, cg.id
+    ";
+    let group_rows = conn
+        .query(
+            groups_sql,
+            &[
+                &check.room_id,
+                &check.day_of_the_week.to_string(),
+                &check.exclude_group_id,
+                &check.start_date,
+                &check.end_date,
+                &check.start_time,
+                &check.end_time,
+                &single_session,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let mut conflicts: Vec<RoomConflict> = group_rows.into_iter().map(|row| RoomConflict {
+        group_id: row.get("id"),
+        module: row.get("module"),
+        day_of_the_week: row.get("day_of_the_week"),
+        start_time: row.get("start_time"),
+        end_time: row.get("end_time"),
+        start_date: row.get("start_date"),
+        end_date: row.get("end_date"),
+        session_date: None,
+    }).collect();
+
+    // Individual sessions of other groups that were moved into this room
+    let sessions_sql = "
+        SELECT
+            gsr.session_date,
+            cg.id, cg.module, cg.day_of_the_week::TEXT as day_of_the_week,
+            cg.start_time, cg.end_time, cg.start_date, cg.end_date
+        FROM group_session_rooms gsr
+        INNER JOIN confirmation_groups cg ON gsr.confirmation_group_id = cg.id
+        WHERE gsr.room_id = $1
+          AND gsr.session_date >= $2
+          AND gsr.session_date <= COALESCE($3::DATE, 'infinity'::DATE)
+          AND ($4::INT IS NULL OR cg.id <> $4)
+          AND ($5::TIME IS NULL OR $6::TIME IS NULL OR cg.start_time IS NULL OR cg.end_time IS NULL
+               OR (cg.start_time < $6 AND $5 < cg.end_time))
+        ORDER BY gsr.session_date
+    ";
+    let session_rows = conn
+        .query(
+            sessions_sql,
+            &[&check.room_id, &check.start_date, &check.end_date, &check.exclude_group_id, &check.start_time, &check.end_time],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let weekday = check.day_of_the_week.to_weekday();
+    conflicts.extend(
+        session_rows
+            .into_iter()
+            .filter(|row| row.get::<_, NaiveDate>("session_date").weekday() == weekday)
+            .map(|row| RoomConflict {
+                group_id: row.get("id"),
+                module: row.get("module"),
+                day_of_the_week: row.get("day_of_the_week"),
+                start_time: row.get("start_time"),
+                end_time: row.get("end_time"),
+                start_date: row.get("start_date"),
+                end_date: row.get("end_date"),
+                session_date: Some(row.get("session_date")),
+            }),
+    );
+
+    Ok(conflicts)
+}
+
+// Rejects a booking with 409 Conflict if the room is already in use. Call it inside the transaction
+// that makes the booking: the room stays locked until it commits, so two requests cannot both find
+// the same slot free.
+async fn ensure_room_is_free(
+    transaction: &impl deadpool_postgres::GenericClient,
+    check: &RoomBookingCheck,
+) -> Result<(), (StatusCode, String)> {
+    transaction
+        .query_opt("SELECT id FROM rooms WHERE id = $1 FOR UPDATE", &[&check.room_id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::BAD_REQUEST, format!("Room with ID {} not found", check.room_id)))?;
+    let conflicts = find_room_conflicts(transaction, check).await?;
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    let descriptions: Vec<String> = conflicts.iter().map(|c| match c.session_date {
+        Some(date) => format!("group {} (module {}) on {}", c.group_id, c.module, date),
+        None => format!("group {} (module {}) every {}", c.group_id, c.module, c.day_of_the_week),
+    }).collect();
+    Err((StatusCode::CONFLICT, format!("Room {} is already booked by {}", check.room_id, descriptions.join(", "))))
+}
+
+const ROOM_SELECT_SQL: &str = "
+    SELECT r.id, r.location_id, l.name as location_name, r.name, r.capacity
+    FROM rooms r
+    INNER JOIN locations l ON r.location_id = l.id
+";
+
+// Handler for `GET /api/locations`
+pub async fn list_locations(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Location>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+
+    let location_rows = conn
+        .query("SELECT id, name, address FROM locations ORDER BY name", &[])
+        .await
+        .map_err(internal_error)?;
+    let room_rows = conn
+        .query(&format!("{} ORDER BY r.name", ROOM_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?;
+    let rooms: Vec<Room> = room_rows.into_iter().map(Room::from).collect();
+
+    let locations = location_rows.into_iter().map(|row| {
+        let id: i32 = row.get("id");
+        Location {
+            id,
+            name: row.get("name"),
+            address: row.get("address"),
+            rooms: rooms.iter().filter(|r| r.location_id == id).cloned().collect(),
+        }
+    }).collect();
+    Ok(Json(locations))
+}
+
+// Handler for `POST /api/locations`
+pub async fn create_location(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateLocation>,
+) -> Result<(StatusCode, Json<Location>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_one(
+            "INSERT INTO locations (name, address) VALUES ($1, $2) RETURNING id, name, address",
+            &[&payload.name, &payload.address],
+        )
+        .await
+        .map_err(internal_error)?;
+    let location = Location {
+        id: row.get("id"),
+        name: row.get("name"),
+        address: row.get("address"),
+        rooms: Vec::new(),
+    };
+    Ok((StatusCode::CREATED, Json(location)))
+}
+
+// Handler for `POST /api/locations/:id/rooms`
+pub async fn create_room(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(location_id): Path<i32>,
+    Json(mut payload): Json<CreateRoom>,
+) -> Result<(StatusCode, Json<Room>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_opt(
+            "INSERT INTO rooms (location_id, name, capacity)
+             SELECT id, $2, $3 FROM locations WHERE id = $1
+             RETURNING id",
+            &[&location_id, &payload.name, &payload.capacity],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Location with ID {} not found", location_id)))?;
+    let new_id: i32 = row.get(0);
+
+    let room_row = conn
+        .query_one(&format!("{} WHERE r.id = $1", ROOM_SELECT_SQL), &[&new_id])
+        .await
+        .map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(Room::from(room_row))))
+}
+
+// Handler for `GET /api/rooms`
+pub async fn list_rooms(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Room>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let rows = conn
+        .query(&format!("{} ORDER BY l.name, r.name", ROOM_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Room::from).collect()))
+}
+
+// Handler for `POST /api/rooms/check`
+// Lists the bookings a proposed schedule would clash with, without changing anything.
+pub async fn check_room_booking(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(payload): Json<RoomBookingCheck>,
+) -> Result<Json<Vec<RoomConflict>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(find_room_conflicts(&conn, &payload).await?))
+}
+
+// Handler for `PUT /api/groups/:id/room`
+pub async fn assign_group_room(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(group_id): Path<i32>,
+    headers: HeaderMap,
+    Json(payload): Json<AssignRoom>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmation_groups", group_id, expected_version, format!("Group with ID {} not found", group_id)).await?;
+
+    let group_row = transaction
+        .query_opt(
+            "SELECT day_of_the_week::TEXT as day_of_the_week, start_time, end_time, start_date, end_date
+             FROM confirmation_groups WHERE id = $1",
+            &[&group_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Group with ID {} not found", group_id)))?;
+
+    if let Some(room_id) = payload.room_id {
+        let day: String = group_row.get("day_of_the_week");
+        let check = RoomBookingCheck {
+            room_id,
+            day_of_the_week: DayOfTheWeek::from_str(&day).map_err(internal_error)?,
+            start_time: group_row.get("start_time"),
+            end_time: group_row.get("end_time"),
+            start_date: group_row.get("start_date"),
+            end_date: group_row.get("end_date"),
+            exclude_group_id: Some(group_id),
+        };
+        ensure_room_is_free(&transaction, &check).await?;
+    }
+
+    let row = transaction
+        .query_one(
+            "UPDATE confirmation_groups SET room_id = $1 WHERE id = $2 RETURNING version",
+            &[&payload.room_id, &group_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+    let version: i32 = row.get("version");
+    Ok((StatusCode::NO_CONTENT, [(header::ETAG, etag::etag(version))]))
+}
+
+// Handler for `PUT /api/groups/:id/sessions/:date/room`
+// Moves a single session to another room. Sending `{"room_id": null}` moves it back to the group's room.
+pub async fn assign_session_room(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((group_id, session_date)): Path<(i32, NaiveDate)>,
+    headers: HeaderMap,
+    Json(payload): Json<AssignRoom>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let holidays = fetch_parish_holidays(&conn).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmation_groups", group_id, expected_version, format!("Group with ID {} not found", group_id)).await?;
+
+    let Some(room_id) = payload.room_id else {
+        transaction
+            .execute(
+                "DELETE FROM group_session_rooms WHERE confirmation_group_id = $1 AND session_date = $2",
+                &[&group_id, &session_date],
+            )
+            .await
+            .map_err(internal_error)?;
+        transaction.commit().await.map_err(internal_error)?;
+        return Ok(StatusCode::NO_CONTENT);
+    };
+
+    let group_row = transaction
+        .query_opt(
+            "SELECT day_of_the_week::TEXT as day_of_the_week, start_time, end_time, start_date, end_date
+             FROM confirmation_groups WHERE id = $1",
+            &[&group_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Group with ID {} not found", group_id)))?;
+
+    let day: String = group_row.get("day_of_the_week");
+    let day = DayOfTheWeek::from_str(&day).map_err(internal_error)?;
+    let plan = schedule::plan_sessions(group_id, day.to_weekday(), group_row.get("start_date"), group_row.get("end_date"), Utc::now().date_naive(), &holidays);
+    if !plan.sessions.contains(&session_date) {
+        return Err((StatusCode::BAD_REQUEST, format!("Group {} has no session on {}", group_id, session_date)));
+    }
+
+    let check = RoomBookingCheck {
+        room_id,
+        day_of_the_week: day,
+        start_time: group_row.get("start_time"),
+        end_time: group_row.get("end_time"),
+        start_date: session_date,
+        end_date: Some(session_date),
+        exclude_group_id: Some(group_id),
+    };
+    ensure_room_is_free(&transaction, &check).await?;
+
+    let sql = "
+        INSERT INTO group_session_rooms (confirmation_group_id, session_date, room_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (confirmation_group_id, session_date) DO UPDATE SET room_id = EXCLUDED.room_id
+    ";
+    transaction.execute(sql, &[&group_id, &session_date, &room_id]).await.map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ===================================================================
+// Catechist Availability Handlers
+// ===================================================================
+
+async fn fetch_availability(conn: &Client, catechist_id: i32) -> Result<Vec<AvailabilitySlot>, (StatusCode, String)> {
+    let sql = "
+        SELECT day_of_the_week::TEXT as day_of_the_week, start_time, end_time
+        FROM catechist_availability
+        WHERE catechist_id = $1
+        ORDER BY day_of_the_week, start_time
+    ";
+    let rows = conn.query(sql, &[&catechist_id]).await.map_err(internal_error)?;
+    Ok(rows.into_iter().map(AvailabilitySlot::from).collect())
+}
+
+// Handler for `GET /api/catechists/:id/availability`
+pub async fn get_catechist_availability(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<CatechistAvailability>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let row = conn
+        .query_opt("SELECT max_concurrent_groups FROM catechists WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Catechist with ID {} not found", id)))?;
+
+    Ok(Json(CatechistAvailability {
+        max_concurrent_groups: row.get("max_concurrent_groups"),
+        slots: fetch_availability(&conn, id).await?,
+    }))
+}
+
+// Handler for `PUT /api/catechists/:id/availability`
+// Replaces the catechist's declared availability as a whole.
+pub async fn update_catechist_availability(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    Json(mut payload): Json<CatechistAvailability>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    payload.validate(Utc::now().date_naive())?;
+
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "catechists", id, expected_version, format!("Catechist with ID {} not found", id)).await?;
+
+    let row = transaction
+        .query_one(
+            "UPDATE catechists SET max_concurrent_groups = $1 WHERE id = $2 RETURNING version",
+            &[&payload.max_concurrent_groups, &id],
+        )
+        .await
+        .map_err(internal_error)?;
+    let version: i32 = row.get("version");
+
+    transaction
+        .execute("DELETE FROM catechist_availability WHERE catechist_id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    for slot in &payload.slots {
+        transaction
+            .execute(
+                "INSERT INTO catechist_availability (catechist_id, day_of_the_week, start_time, end_time)
+                 VALUES ($1, CAST($2 AS VARCHAR)::day_of_week_enum, $3, $4)",
+                &[&id, &slot.day_of_the_week.to_string(), &slot.start_time, &slot.end_time],
+            )
+            .await
+            .map_err(internal_error)?;
+    }
+    transaction.commit().await.map_err(internal_error)?;
+
+    let availability = CatechistAvailability {
+        max_concurrent_groups: payload.max_concurrent_groups,
+        slots: fetch_availability(&conn, id).await?,
+    };
+    Ok(([(header::ETAG, etag::etag(version))], Json(availability)))
+}
+
+// Handler for `GET /api/catechists/availability?day=Monday&start_time=18:00:00&end_time=19:00:00`
+// Lists active catechists who declared themselves available for the whole slot, are below their
+// maximum number of concurrent groups, and are not already leading a group at that time.
+pub async fn find_available_catechists(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(query): Query<AvailabilityQuery>,
+) -> Result<Json<Vec<Catechist>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+
+    let sql = format!("{} {}", CATECHIST_SELECT_SQL, "
+        WHERE c.currently_active = TRUE
+          AND (c.max_concurrent_groups IS NULL OR COALESCE(w.active_group_count, 0) < c.max_concurrent_groups)
+          AND EXISTS (
+              SELECT 1 FROM catechist_availability ca
+              WHERE ca.catechist_id = c.id
+                AND ca.day_of_the_week = CAST($1 AS VARCHAR)::day_of_week_enum
+                AND ($2::TIME IS NULL OR ca.start_time <= $2)
+                AND ($3::TIME IS NULL OR ca.end_time >= $3)
+          )
+          AND NOT EXISTS (
+              SELECT 1 FROM confirmation_groups cg
+              WHERE cg.catechist_id = c.id
+                AND cg.day_of_the_week = CAST($1 AS VARCHAR)::day_of_week_enum
+                AND (cg.end_date IS NULL OR cg.end_date >= CURRENT_DATE)
+                AND ($2::TIME IS NULL OR $3::TIME IS NULL OR cg.start_time IS NULL OR cg.end_time IS NULL
+                     OR (cg.start_time < $3 AND $2 < cg.end_time))
+          )
+        ORDER BY COALESCE(w.active_group_count, 0), c.full_name
+    ");
+
+    let rows = conn
+        .query(&sql, &[&query.day.to_string(), &query.start_time, &query.end_time])
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Catechist::from).collect()))
+}
+
+// ===================================================================
+// Group Assignment Handlers
+// ===================================================================
+
+// Handler for `GET /api/confirmands/:id/preferred-days`
+pub async fn get_preferred_days(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<DayOfTheWeek>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let rows = conn
+        .query(
+            "SELECT day_of_the_week::TEXT FROM confirmand_preferred_days WHERE confirmand_id = $1 ORDER BY day_of_the_week",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?;
+    let days = rows
+        .into_iter()
+        .filter_map(|row| DayOfTheWeek::from_str(&row.get::<_, String>(0)).ok())
+        .collect();
+    Ok(Json(days))
+}
+
+// Handler for `PUT /api/confirmands/:id/preferred-days`
+pub async fn update_preferred_days(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<Vec<DayOfTheWeek>>,
+) -> Result<Json<Vec<DayOfTheWeek>>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+
+    let exists = transaction.query_opt("SELECT id FROM confirmands WHERE id = $1", &[&id])
+        .await.map_err(internal_error)?;
+    if exists.is_none() {
+        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)));
+    }
+
+    transaction
+        .execute("DELETE FROM confirmand_preferred_days WHERE confirmand_id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    for day in &payload {
+        transaction
+            .execute(
+                "INSERT INTO confirmand_preferred_days (confirmand_id, day_of_the_week)
+                 VALUES ($1, CAST($2 AS VARCHAR)::day_of_week_enum) ON CONFLICT DO NOTHING",
+                &[&id, &day.to_string()],
+            )
+            .await
+            .map_err(internal_error)?;
+    }
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(Json(payload))
+}
+
+// Participants waiting for a group, with the module they took last (NULL if they never had a group)
+// and the highest module of any group. Same "latest group" join as `list_confirmands`. Archived
+// participants and people still in an open group are not waiting. Neither is anyone who has received
+// Confirmation: there is nothing left for them to prepare for. Callers leave out whoever completed
+// the highest module with `assignment::next_module`.
+// `$1` is today; callers may append more `AND` conditions.
+const WAITING_FOR_GROUP_SQL: &str = "
+    WITH LatestGroup AS (
+        SELECT DISTINCT ON (c.id)
+            c.id, c.full_name, c.birth_date, c.household_id,
+            cg.id as group_id, cg.module
+        FROM confirmands c
+        LEFT JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
+        LEFT JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+        WHERE c.archived_at IS NULL
+          AND NOT EXISTS (
+            SELECT 1 FROM confirmand_sacraments cs
+            INNER JOIN sacraments s ON cs.sacrament_id = s.id
+            WHERE cs.confirmand_id = c.id AND s.code = 'confirmation'
+        )
+        ORDER BY c.id, cg.start_date DESC
+    )
+    SELECT
+        lg.id, lg.full_name, lg.birth_date, lg.household_id, lg.group_id, lg.module,
+        (SELECT MAX(module) FROM confirmation_groups) as highest_module,
+        ARRAY(
+            SELECT p.day_of_the_week::TEXT FROM confirmand_preferred_days p WHERE p.confirmand_id = lg.id
+        ) as preferred_days
+    FROM LatestGroup lg
+    WHERE NOT EXISTS (
+            SELECT 1 FROM confirmand_confirmation_groups o
+            INNER JOIN confirmation_groups og ON o.confirmation_group_id = og.id
+            WHERE o.confirmand_id = lg.id AND (og.end_date IS NULL OR og.end_date >= $1)
+        )
+";
+
+// Handler for `POST /api/groups/assignments/propose`
+// Proposes a balanced allocation of every confirmand without a current group. Nothing is saved;
+// review the proposal and send it to `/apply`.
+pub async fn propose_group_assignments(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<ProposeAssignments>,
+) -> Result<Json<AssignmentProposal>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = state.get().await.map_err(internal_error)?;
+    let today = Utc::now().date_naive();
+
+    let candidate_rows = conn.query(WAITING_FOR_GROUP_SQL, &[&today]).await.map_err(internal_error)?;
+    let candidates: Vec<Candidate> = candidate_rows
+        .into_iter()
+        .filter_map(|row| {
+            // Participants who completed the highest module are done and are not proposed again.
+            let module = assignment::next_module(row.get("module"), row.get("highest_module"))?;
+            let preferred_days: Vec<String> = row.get("preferred_days");
+            Some(Candidate {
+                id: row.get("id"),
+                full_name: row.get("full_name"),
+                age: assignment::age_on(row.get("birth_date"), today),
+                module,
+                preferred_days: preferred_days.iter().filter_map(|d| DayOfTheWeek::from_str(d).ok()).collect(),
+                household_id: row.get("household_id"),
+            })
+        })
+        .filter(|c| payload.module.is_none_or(|m| c.module == m))
+        .collect();
+
+    let groups_sql = "
+        SELECT
+            cg.id, cg.module, cg.day_of_the_week::TEXT as day_of_the_week,
+            COUNT(c.id) as member_count,
+            COALESCE(SUM(EXTRACT(YEAR FROM AGE($1::DATE, c.birth_date))), 0)::FLOAT8 as age_sum
+        FROM confirmation_groups cg
+        LEFT JOIN confirmand_confirmation_groups ccg ON cg.id = ccg.confirmation_group_id
+        LEFT JOIN confirmands c ON ccg.confirmand_id = c.id AND c.archived_at IS NULL
+        WHERE cg.end_date IS NULL OR cg.end_date >= $1
+        GROUP BY cg.id
+    ";
+    let group_rows = conn.query(groups_sql, &[&today]).await.map_err(internal_error)?;
+    let groups: Vec<OpenGroup> = group_rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(OpenGroup {
+                id: row.get("id"),
+                module: row.get("module"),
+                day_of_the_week: DayOfTheWeek::from_str(&row.get::<_, String>("day_of_the_week")).ok()?,
+                member_count: row.get("member_count"),
+                age_sum: row.get("age_sum"),
+            })
+        })
+        .collect();
+
+    Ok(Json(assignment::propose(candidates, groups, payload.target_group_size)))
+}
+
+// Handler for `POST /api/groups/assignments/apply`
+// Applies all assignments in one transaction: either every confirmand is enrolled or none is.
+pub async fn apply_group_assignments(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(payload): Json<ApplyAssignments>,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let today = Utc::now().date_naive();
+
+    // Locks the target groups and participants first, so a concurrent apply or enrollment cannot
+    // change their membership between the checks below and the inserts.
+    let group_ids: Vec<i32> = payload.assignments.iter().map(|a| a.group_id).collect();
+    let open_rows = transaction
+        .query(
+            "SELECT id, module FROM confirmation_groups WHERE id = ANY($1) AND (end_date IS NULL OR end_date >= $2)
+             ORDER BY id FOR UPDATE",
+            &[&group_ids, &today],
+        )
+        .await
+        .map_err(internal_error)?;
+    let open_groups: HashMap<i32, i16> = open_rows.iter().map(|row| (row.get("id"), row.get("module"))).collect();
+
+    let confirmand_ids: Vec<i32> = payload.assignments.iter().map(|a| a.confirmand_id).collect();
+    let confirmand_rows = transaction
+        .query(
+            "SELECT id, archived_at IS NOT NULL as archived FROM confirmands WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+            &[&confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    let known_confirmands: HashMap<i32, bool> = confirmand_rows.iter().map(|row| (row.get("id"), row.get("archived"))).collect();
+    let enrollment_rows = transaction
+        .query(
+            "SELECT confirmand_id, confirmation_group_id FROM confirmand_confirmation_groups WHERE confirmand_id = ANY($1)",
+            &[&confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    let enrolled: HashSet<(i32, i32)> = enrollment_rows.iter().map(|row| (row.get(0), row.get(1))).collect();
+    // The module each participant who is still waiting for a group should take next.
+    let waiting_rows = transaction
+        .query(&format!("{} AND lg.id = ANY($2)", WAITING_FOR_GROUP_SQL), &[&today, &confirmand_ids])
+        .await
+        .map_err(internal_error)?;
+    let next_modules: HashMap<i32, i16> = waiting_rows
+        .iter()
+        .filter_map(|row| Some((row.get("id"), assignment::next_module(row.get("module"), row.get("highest_module"))?)))
+        .collect();
+
+    let mut assigned = HashSet::new();
+    let mut added = 0;
+    for assignment in &payload.assignments {
+        let Some(&group_module) = open_groups.get(&assignment.group_id) else {
+            return Err((StatusCode::BAD_REQUEST, format!("Group {} does not exist or has already ended", assignment.group_id)));
+        };
+        match known_confirmands.get(&assignment.confirmand_id) {
+            None => return Err((StatusCode::BAD_REQUEST, format!("Participant with ID {} not found", assignment.confirmand_id))),
+            Some(true) => return Err((StatusCode::CONFLICT, format!("Participant with ID {} is archived", assignment.confirmand_id))),
+            Some(false) => {}
+        }
+        // Applying the same proposal twice is harmless.
+        if enrolled.contains(&(assignment.confirmand_id, assignment.group_id)) {
+            continue;
+        }
+        if !assigned.insert(assignment.confirmand_id) {
+            return Err((StatusCode::BAD_REQUEST, format!("Participant with ID {} is assigned more than once", assignment.confirmand_id)));
+        }
+        let Some(&next_module) = next_modules.get(&assignment.confirmand_id) else {
+            return Err((
+                StatusCode::CONFLICT,
+                format!(
+                    "Participant with ID {} is already confirmed, in an open group or has completed every module",
+                    assignment.confirmand_id
+                ),
+            ));
+        };
+        if group_module != next_module {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "Participant with ID {} needs module {}, but group {} is for module {}",
+                    assignment.confirmand_id, next_module, assignment.group_id, group_module
+                ),
+            ));
+        }
+        added += transaction
+            .execute(
+                "INSERT INTO confirmand_confirmation_groups (confirmand_id, confirmation_group_id) VALUES ($1, $2)",
+                &[&assignment.confirmand_id, &assignment.group_id],
+            )
+            .await
+            .map_err(internal_error)?;
+    }
+    transaction.commit().await.map_err(internal_error)?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "participants_assigned": added,
+        "already_assigned": payload.assignments.len() as u64 - added,
+    })))
+}
+
+// ===================================================================
+// Catechetical Year Handlers
+// ===================================================================
+
+const YEAR_SELECT_SQL: &str = "SELECT id, name, start_date, end_date, is_active FROM catechetical_years";
+
+// Inserts a year and attaches the groups without a year whose start_date falls inside it.
+async fn insert_catechetical_year(
+    transaction: &Transaction<'_>,
+    payload: &CreateCatecheticalYear,
+) -> Result<CatecheticalYear, (StatusCode, String)> {
+    let row = transaction
+        .query_one(
+            "INSERT INTO catechetical_years (name, start_date, end_date) VALUES ($1, $2, $3)
+             RETURNING id, name, start_date, end_date, is_active",
+            &[&payload.name.trim(), &payload.start_date, &payload.end_date],
+        )
+        .await
+        .map_err(internal_error)?;
+    let year = CatecheticalYear::from(row);
+
+    transaction
+        .execute(
+            "UPDATE confirmation_groups SET catechetical_year_id = $1
+             WHERE catechetical_year_id IS NULL AND start_date BETWEEN $2 AND $3",
+            &[&year.id, &year.start_date, &year.end_date],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(year)
+}
+
+// Handler for `GET /api/years`
+pub async fn list_catechetical_years(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<CatecheticalYear>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let rows = conn
+        .query(&format!("{} ORDER BY start_date DESC", YEAR_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(CatecheticalYear::from).collect()))
+}
+
+// Handler for `POST /api/years`
+pub async fn create_catechetical_year(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateCatecheticalYear>,
+) -> Result<(StatusCode, Json<CatecheticalYear>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let year = insert_catechetical_year(&transaction, &payload).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(year)))
+}
+
+// Handler for `POST /api/years/:id/activate`
+pub async fn activate_catechetical_year(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<CatecheticalYear>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+
+    transaction
+        .execute("UPDATE catechetical_years SET is_active = FALSE WHERE is_active AND id <> $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    let row = transaction
+        .query_opt(
+            "UPDATE catechetical_years SET is_active = TRUE WHERE id = $1
+             RETURNING id, name, start_date, end_date, is_active",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Catechetical year with ID {} not found", id)))?;
+
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(Json(CatecheticalYear::from(row)))
+}
+
+// Handler for `POST /api/years/rollover`
+// Closes the active year (open-ended groups get the year's end date), creates and activates the new
+// year and, if requested, re-creates the outgoing groups in it shifted by the same number of days.
+// Copies keep their room only where it is still free in the new year; the others are left without
+// one. Members are not carried over; use the group assignment engine for that.
+pub async fn rollover_catechetical_year(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<YearRollover>,
+) -> Result<(StatusCode, Json<CatecheticalYear>), (StatusCode, String)> {
+    payload.year.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+
+    let previous = transaction
+        .query_opt(&format!("{} WHERE is_active", YEAR_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?
+        .map(CatecheticalYear::from);
+
+    if let Some(previous) = &previous {
+        if payload.year.start_date <= previous.start_date {
+            return Err((StatusCode::BAD_REQUEST, "The new year must start after the active year".to_string()));
+        }
+        transaction
+            .execute(
+                "UPDATE confirmation_groups SET end_date = $2 WHERE catechetical_year_id = $1 AND end_date IS NULL",
+                &[&previous.id, &previous.end_date],
+            )
+            .await
+            .map_err(internal_error)?;
+        transaction
+            .execute("UPDATE catechetical_years SET is_active = FALSE WHERE id = $1", &[&previous.id])
+            .await
+            .map_err(internal_error)?;
+    }
+
+    let new_year = insert_catechetical_year(&transaction, &payload.year).await?;
+    transaction
+        .execute("UPDATE catechetical_years SET is_active = TRUE WHERE id = $1", &[&new_year.id])
+        .await
+        .map_err(internal_error)?;
+
+    if let (true, Some(previous)) = (payload.copy_groups, &previous) {
+        let shift_days = (new_year.start_date - previous.start_date).num_days() as i32;
+        // Rooms stay locked until commit, as in `ensure_room_is_free`.
+        transaction
+            .execute(
+                "SELECT id FROM rooms
+                 WHERE id IN (SELECT room_id FROM confirmation_groups WHERE catechetical_year_id = $1)
+                 ORDER BY id FOR UPDATE",
+                &[&previous.id],
+            )
+            .await
+            .map_err(internal_error)?;
+        let copies = transaction
+            .query(
+                "INSERT INTO confirmation_groups
+                    (module, catechist_id, day_of_the_week, group_link, start_date, end_date,
+                     start_time, end_time, room_id, catechetical_year_id)
+                 SELECT module, catechist_id, day_of_the_week, group_link, start_date + $2, NULL,
+                        start_time, end_time, room_id, $3
+                 FROM confirmation_groups
+                 WHERE catechetical_year_id = $1
+                 ORDER BY id
+                 RETURNING id, room_id, day_of_the_week::TEXT as day_of_the_week, start_time, end_time, start_date",
+                &[&previous.id, &shift_days, &new_year.id],
+            )
+            .await
+            .map_err(internal_error)?;
+        // Copies get their room back one at a time, so they are also checked against each other:
+        // groups that took turns in a room last year are open-ended until their year is closed.
+        let copy_ids: Vec<i32> = copies.iter().map(|copy| copy.get("id")).collect();
+        transaction
+            .execute("UPDATE confirmation_groups SET room_id = NULL WHERE id = ANY($1)", &[&copy_ids])
+            .await
+            .map_err(internal_error)?;
+        for copy in &copies {
+            let Some(room_id) = copy.get::<_, Option<i32>>("room_id") else {
+                continue;
+            };
+            let copy_id: i32 = copy.get("id");
+            let day: String = copy.get("day_of_the_week");
+            let check = RoomBookingCheck {
+                room_id,
+                day_of_the_week: DayOfTheWeek::from_str(&day).map_err(internal_error)?,
+                start_time: copy.get("start_time"),
+                end_time: copy.get("end_time"),
+                start_date: copy.get("start_date"),
+                end_date: None,
+                exclude_group_id: Some(copy_id),
+            };
+            if find_room_conflicts(&transaction, &check).await?.is_empty() {
+                transaction
+                    .execute("UPDATE confirmation_groups SET room_id = $1 WHERE id = $2", &[&room_id, &copy_id])
+                    .await
+                    .map_err(internal_error)?;
+            }
+        }
+    }
+
+    transaction.commit().await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(CatecheticalYear { is_active: true, ..new_year })))
+}
+
+// ===================================================================
+// Reports Handlers
+// ===================================================================
+
+// The overview runs several aggregate queries, so results are reused for a short while.
+const OVERVIEW_CACHE_TTL: Duration = Duration::from_secs(60);
+// Keyed by the catechetical year filter; each entry remembers when it was computed.
+type OverviewCache = HashMap<Option<i32>, (Instant, ReportsOverview)>;
+static OVERVIEW_CACHE: Lazy<Mutex<OverviewCache>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+// Handler for `GET /api/reports/overview` (optionally `?year=<id>` to scope it to one catechetical year)
+pub async fn get_reports_overview(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(filter): Query<YearFilter>,
+) -> Result<Json<ReportsOverview>, (StatusCode, String)> {
+    if let Some((cached_at, overview)) = OVERVIEW_CACHE.lock().unwrap().get(&filter.year)
+        && cached_at.elapsed() < OVERVIEW_CACHE_TTL
+    {
+        return Ok(Json(overview.clone()));
+    }
+
+    let mut conn = state.get().await.map_err(internal_error)?;
+    // A read-only transaction gives all the figures the same snapshot.
+    let transaction = conn.build_transaction().read_only(true).start().await.map_err(internal_error)?;
+
+    // Archived participants are left out of every figure.
+    let enrollment_sql = "
+        SELECT cy.id as catechetical_year_id, cy.name as year_name, cg.module,
+               COUNT(DISTINCT ccg.confirmand_id) as participant_count
+        FROM confirmation_groups cg
+        INNER JOIN confirmand_confirmation_groups ccg ON cg.id = ccg.confirmation_group_id
+        INNER JOIN confirmands c ON ccg.confirmand_id = c.id AND c.archived_at IS NULL
+        LEFT JOIN catechetical_years cy ON cg.catechetical_year_id = cy.id
+        WHERE $1::INT IS NULL OR cg.catechetical_year_id = $1
+        GROUP BY cy.id, cy.name, cy.start_date, cg.module
+        ORDER BY cy.start_date NULLS FIRST, cg.module
+    ";
+    let enrollment_by_module = transaction
+        .query(enrollment_sql, &[&filter.year])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| ModuleEnrollment {
+            catechetical_year_id: row.get("catechetical_year_id"),
+            year_name: row.get("year_name"),
+            module: row.get("module"),
+            participant_count: row.get("participant_count"),
+        })
+        .collect();
+
+    // Registrations are counted over the year's date range when a year is given, otherwise the last 24 months.
+    let registrations_sql = "
+        SELECT date_trunc('month', c.creation_date)::DATE as month, COUNT(*) as count
+        FROM confirmands c
+        WHERE c.archived_at IS NULL
+          AND (
+              ($1::INT IS NULL AND c.creation_date >= date_trunc('month', NOW()) - INTERVAL '23 months')
+              OR EXISTS (
+                  SELECT 1 FROM catechetical_years cy
+                  WHERE cy.id = $1 AND c.creation_date::DATE BETWEEN cy.start_date AND cy.end_date
+              )
+          )
+        GROUP BY 1
+        ORDER BY 1
+    ";
+    let registrations_by_month = transaction
+        .query(registrations_sql, &[&filter.year])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| MonthlyCount { month: row.get("month"), count: row.get("count") })
+        .collect();
+
+    // A finished enrollment counts as completed if the participant later joined a higher module
+    // or received Confirmation; otherwise it counts as a drop-out.
+    let outcomes_sql = "
+        WITH Finished AS (
+            SELECT
+                cg.module,
+                EXISTS (
+                    SELECT 1
+                    FROM confirmand_confirmation_groups later_ccg
+                    INNER JOIN confirmation_groups later_cg ON later_ccg.confirmation_group_id = later_cg.id
+                    WHERE later_ccg.confirmand_id = ccg.confirmand_id AND later_cg.module > cg.module
+                ) OR EXISTS (
+                    SELECT 1
+                    FROM confirmand_sacraments cs
+                    INNER JOIN sacraments s ON cs.sacrament_id = s.id
+                    WHERE cs.confirmand_id = ccg.confirmand_id AND s.code = 'confirmation'
+                ) as continued
+            FROM confirmand_confirmation_groups ccg
+            INNER JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+            INNER JOIN confirmands c ON ccg.confirmand_id = c.id AND c.archived_at IS NULL
+            WHERE cg.end_date < CURRENT_DATE
+              AND ($1::INT IS NULL OR cg.catechetical_year_id = $1)
+        )
+        SELECT
+            module,
+            COUNT(*) as finished_count,
+            COUNT(*) FILTER (WHERE continued) as completed_count
+        FROM Finished
+        GROUP BY module
+        ORDER BY module
+    ";
+    let module_outcomes = transaction
+        .query(outcomes_sql, &[&filter.year])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| {
+            let finished_count: i64 = row.get("finished_count");
+            let completed_count: i64 = row.get("completed_count");
+            let dropped_out_count = finished_count - completed_count;
+            let rate = |n: i64| if finished_count == 0 { 0.0 } else { n as f64 / finished_count as f64 };
+            ModuleOutcome {
+                module: row.get("module"),
+                finished_count,
+                completed_count,
+                dropped_out_count,
+                completion_rate: rate(completed_count),
+                dropout_rate: rate(dropped_out_count),
+            }
+        })
+        .collect();
+
+    // Confirmations are attributed to the catechetical year their date falls in. Records without a date
+    // fall back to the year of the participant's latest group.
+    let confirmations_sql = "
+        WITH LatestGroup AS (
+            SELECT DISTINCT ON (ccg.confirmand_id)
+                ccg.confirmand_id, cg.catechetical_year_id
+            FROM confirmand_confirmation_groups ccg
+            INNER JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+            ORDER BY ccg.confirmand_id, cg.start_date DESC
+        )
+        SELECT cy.id as catechetical_year_id, cy.name as year_name, COUNT(*) as count
+        FROM confirmand_sacraments cs
+        INNER JOIN sacraments s ON cs.sacrament_id = s.id
+        INNER JOIN confirmands c ON cs.confirmand_id = c.id AND c.archived_at IS NULL
+        LEFT JOIN LatestGroup lg ON cs.confirmand_id = lg.confirmand_id
+        LEFT JOIN catechetical_years cy ON cy.id = COALESCE(
+            (SELECT by_date.id FROM catechetical_years by_date
+             WHERE cs.received_date BETWEEN by_date.start_date AND by_date.end_date
+             ORDER BY by_date.start_date DESC LIMIT 1),
+            lg.catechetical_year_id
+        )
+        WHERE s.code = 'confirmation'
+          AND ($1::INT IS NULL OR cy.id = $1)
+        GROUP BY cy.id, cy.name, cy.start_date
+        ORDER BY cy.start_date NULLS FIRST
+    ";
+    let confirmations_by_year = transaction
+        .query(confirmations_sql, &[&filter.year])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| YearlyCount {
+            catechetical_year_id: row.get("catechetical_year_id"),
+            year_name: row.get("year_name"),
+            count: row.get("count"),
+        })
+        .collect();
+
+    let age_sql = "
+        WITH Ages AS (
+            SELECT EXTRACT(YEAR FROM AGE(CURRENT_DATE, c.birth_date))::INT as age
+            FROM confirmands c
+            WHERE c.archived_at IS NULL
+              AND ($1::INT IS NULL OR EXISTS (
+                SELECT 1
+                FROM confirmand_confirmation_groups ccg
+                INNER JOIN confirmation_groups cg ON ccg.confirmation_group_id = cg.id
+                WHERE ccg.confirmand_id = c.id AND cg.catechetical_year_id = $1
+              ))
+        )
+        SELECT label, COUNT(*) as count
+        FROM (
+            SELECT
+                CASE
+                    WHEN age < 14 THEN 1 WHEN age < 18 THEN 2 WHEN age < 26 THEN 3
+                    WHEN age < 36 THEN 4 WHEN age < 51 THEN 5 ELSE 6
+                END as bucket,
+                CASE
+                    WHEN age < 14 THEN 'Under 14' WHEN age < 18 THEN '14-17' WHEN age < 26 THEN '18-25'
+                    WHEN age < 36 THEN '26-35' WHEN age < 51 THEN '36-50' ELSE 'Over 50'
+                END as label
+            FROM Ages
+        ) buckets
+        GROUP BY bucket, label
+        ORDER BY bucket
+    ";
+    let age_distribution = transaction
+        .query(age_sql, &[&filter.year])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| AgeBucket { label: row.get("label"), count: row.get("count") })
+        .collect();
+
+    transaction.commit().await.map_err(internal_error)?;
+
+    let overview = ReportsOverview {
+        generated_at: Utc::now(),
+        enrollment_by_module,
+        registrations_by_month,
+        module_outcomes,
+        confirmations_by_year,
+        age_distribution,
+    };
+    OVERVIEW_CACHE.lock().unwrap().insert(filter.year, (Instant::now(), overview.clone()));
+    Ok(Json(overview))
+}
+
+// ===================================================================
+// Confirmation Eligibility Handlers
+// ===================================================================
+
+const ELIGIBILITY_REQUIREMENT_SELECT_SQL: &str = "
+    SELECT id, kind::TEXT as kind, label, sacrament_code, module_count, minimum_age, enabled
+    FROM eligibility_requirements
+";
+
+async fn fetch_eligibility_requirements(
+    conn: &impl deadpool_postgres::GenericClient,
+) -> Result<Vec<EligibilityRequirement>, (StatusCode, String)> {
+    let rows = conn
+        .query(&format!("{} ORDER BY position, id", ELIGIBILITY_REQUIREMENT_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?;
+    Ok(rows.into_iter().map(EligibilityRequirement::from).collect())
+}
+
+// Eligibility reports for the given participants, by name; unknown ids are left out. Only what the
+// checklist looks at is loaded, for everyone at once.
+async fn fetch_eligibility_reports(
+    conn: &impl deadpool_postgres::GenericClient,
+    confirmand_ids: &[i32],
+) -> Result<Vec<EligibilityReport>, (StatusCode, String)> {
+    let rows = conn
+        .query(
+            "SELECT id, full_name, birth_date FROM confirmands WHERE id = ANY($1) ORDER BY full_name",
+            &[&confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let sacrament_rows = conn
+        .query(&format!("{} WHERE cs.confirmand_id = ANY($1) ORDER BY s.id", SACRAMENT_RECORD_SELECT_SQL), &[&confirmand_ids])
+        .await
+        .map_err(internal_error)?;
+    let mut sacraments: HashMap<i32, Vec<SacramentRecord>> = HashMap::new();
+    for row in sacrament_rows {
+        sacraments.entry(row.get("confirmand_id")).or_default().push(SacramentRecord::from(row));
+    }
+
+    let group_rows = conn
+        .query(
+            "SELECT ccg.confirmand_id, cg.id, cg.module, cg.start_date, cg.end_date
+             FROM confirmation_groups cg
+             INNER JOIN confirmand_confirmation_groups ccg ON cg.id = ccg.confirmation_group_id
+             WHERE ccg.confirmand_id = ANY($1)",
+            &[&confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    let mut group_history: HashMap<i32, Vec<GroupSummary>> = HashMap::new();
+    for row in group_rows {
+        group_history.entry(row.get("confirmand_id")).or_default().push(GroupSummary {
+            id: row.get("id"),
+            module: row.get("module"),
+            start_date: row.get("start_date"),
+            end_date: row.get("end_date"),
+            catechist_name: None,
+        });
+    }
+
+    let sponsors: HashMap<i32, String> = conn
+        .query("SELECT confirmand_id, full_name FROM sponsors WHERE confirmand_id = ANY($1)", &[&confirmand_ids])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(|row| (row.get("confirmand_id"), row.get("full_name")))
+        .collect();
+
+    let requirements = fetch_eligibility_requirements(conn).await?;
+    let today = Utc::now().date_naive();
+    let reports = rows
+        .iter()
+        .map(|row| {
+            let id: i32 = row.get("id");
+            let full_name: String = row.get("full_name");
+            let participant = eligibility::Participant {
+                id,
+                full_name: &full_name,
+                birth_date: row.get("birth_date"),
+                sacraments: sacraments.get(&id).map_or(&[], Vec::as_slice),
+                group_history: group_history.get(&id).map_or(&[], Vec::as_slice),
+                sponsor_name: sponsors.get(&id).map(String::as_str),
+            };
+            eligibility::evaluate(participant, &requirements, today)
+        })
+        .collect();
+    Ok(reports)
+}
+
+// Handler for `GET /api/eligibility/requirements`
+pub async fn list_eligibility_requirements(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<EligibilityRequirement>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_eligibility_requirements(&conn).await?))
+}
+
+// Handler for `PUT /api/eligibility/requirements`
+// Replaces the whole checklist; the order of the list is the order requirements are reported in.
+pub async fn update_eligibility_requirements(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<Vec<CreateEligibilityRequirement>>,
+) -> Result<Json<Vec<EligibilityRequirement>>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    transaction.execute("DELETE FROM eligibility_requirements", &[]).await.map_err(internal_error)?;
+    for (position, requirement) in payload.iter().enumerate() {
+        transaction
+            .execute(
+                "INSERT INTO eligibility_requirements
+                    (position, kind, label, sacrament_code, module_count, minimum_age, enabled)
+                 VALUES ($1, CAST($2 AS VARCHAR)::eligibility_requirement_kind_enum, $3, $4, $5, $6, $7)",
+                &[
+                    &(position as i32),
+                    &requirement.kind.to_string(),
+                    &requirement.label.trim(),
+                    &requirement.sacrament_code,
+                    &requirement.module_count,
+                    &requirement.minimum_age,
+                    &requirement.enabled,
+                ],
+            )
+            .await
+            .map_err(internal_error)?;
+    }
+    transaction.commit().await.map_err(internal_error)?;
+
+    Ok(Json(fetch_eligibility_requirements(&conn).await?))
+}
+
+// Handler for `GET /api/confirmands/:id/eligibility`
+pub async fn get_confirmand_eligibility(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<EligibilityReport>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let requirements = fetch_eligibility_requirements(&conn).await?;
+    let details = fetch_participant_details(&conn, id).await?;
+    Ok(Json(eligibility::evaluate(&details, &requirements, Utc::now().date_naive())))
+}
+
+// Handler for `GET /api/groups/:id/eligibility`
+// Evaluates the checklist for every member of the group.
+pub async fn get_group_eligibility(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(group_id): Path<i32>,
+) -> Result<Json<Vec<EligibilityReport>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let member_ids: Vec<i32> = conn
+        .query(
+            "SELECT c.id
+             FROM confirmands c
+             INNER JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
+             WHERE ccg.confirmation_group_id = $1 AND c.archived_at IS NULL",
+            &[&group_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    Ok(Json(fetch_eligibility_reports(&conn, &member_ids).await?))
+}
+
+// ===================================================================
+// Sponsor Handlers
+// ===================================================================
+
+const SPONSOR_SELECT_SQL: &str = "
+    SELECT id, confirmand_id, full_name, email, phone_number, relationship::TEXT as relationship,
+           birth_date, is_confirmed, has_first_communion, marital_status::TEXT as marital_status, parish
+    FROM sponsors
+";
+
+// Handler for `GET /api/confirmands/:id/sponsor`
+pub async fn get_sponsor(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Sponsor>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = format!("{} WHERE confirmand_id = $1", SPONSOR_SELECT_SQL);
+    let row = conn
+        .query_opt(&sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Participant with ID {} has no sponsor", id)))?;
+    Ok(Json(Sponsor::from(row)))
+}
+
+// Handler for `PUT /api/confirmands/:id/sponsor`
+// A confirmand has a single sponsor, so this creates or replaces it.
+pub async fn upsert_sponsor(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(mut payload): Json<UpsertSponsor>,
+) -> Result<Json<Sponsor>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+
+    let conn = connect_as(&state, &user).await?;
+    let exists = conn.query_opt("SELECT id FROM confirmands WHERE id = $1", &[&id])
+        .await.map_err(internal_error)?;
+    if exists.is_none() {
+        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)));
+    }
+
+    let sql = "
+        WITH saved AS (
+            INSERT INTO sponsors (
+                confirmand_id, full_name, email, phone_number, relationship,
+                birth_date, is_confirmed, has_first_communion, marital_status, parish
+            )
+            VALUES (
+                $1, $2, $3, $4, CAST($5 AS VARCHAR)::sponsor_relationship_enum,
+                $6, $7, $8, CAST($9 AS VARCHAR)::marital_status_enum, $10
+            )
+            ON CONFLICT (confirmand_id) DO UPDATE SET
+                full_name = EXCLUDED.full_name,
+                email = EXCLUDED.email,
+                phone_number = EXCLUDED.phone_number,
+                relationship = EXCLUDED.relationship,
+                birth_date = EXCLUDED.birth_date,
+                is_confirmed = EXCLUDED.is_confirmed,
+                has_first_communion = EXCLUDED.has_first_communion,
+                marital_status = EXCLUDED.marital_status,
+                parish = EXCLUDED.parish
+            RETURNING *
+        )
+        SELECT id, confirmand_id, full_name, email, phone_number, relationship::TEXT as relationship,
+               birth_date, is_confirmed, has_first_communion, marital_status::TEXT as marital_status, parish
+        FROM saved
+    ";
+    let row = conn
+        .query_one(
+            sql,
+            &[
+                &id,
+                &payload.full_name.trim(),
+                &payload.email,
+                &payload.phone_number,
+                &payload.relationship.to_string(),
+                &payload.birth_date,
+                &payload.is_confirmed,
+                &payload.has_first_communion,
+                &payload.marital_status.to_string(),
+                &payload.parish,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(Sponsor::from(row)))
+}
+
+// Handler for `DELETE /api/confirmands/:id/sponsor`
+pub async fn delete_sponsor(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+    let deleted = transaction
+        .execute("DELETE FROM sponsors WHERE confirmand_id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} has no sponsor", id)));
+    }
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ===================================================================
+// Guardian Handlers
+// ===================================================================
+
+const GUARDIAN_SELECT_SQL: &str = "
+    SELECT g.id, g.full_name, g.phone_number, g.email, cg.relationship::TEXT as relationship,
+           cg.is_primary_contact, cg.is_consent_holder
+    FROM confirmand_guardians cg
+    INNER JOIN guardians g ON cg.guardian_id = g.id
+";
+
+async fn fetch_guardian(
+    conn: &impl deadpool_postgres::GenericClient,
+    confirmand_id: i32,
+    guardian_id: i32,
+) -> Result<Guardian, (StatusCode, String)> {
+    let sql = format!("{} WHERE cg.confirmand_id = $1 AND cg.guardian_id = $2", GUARDIAN_SELECT_SQL);
+    let row = conn
+        .query_opt(&sql, &[&confirmand_id, &guardian_id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Guardian {} is not linked to participant {}", guardian_id, confirmand_id)))?;
+    Ok(Guardian::from(row))
+}
+
+// A confirmand has at most one primary contact, so marking a new one demotes the previous one.
+async fn clear_primary_contact(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    except_guardian_id: i32,
+) -> Result<(), (StatusCode, String)> {
+    transaction
+        .execute(
+            "UPDATE confirmand_guardians SET is_primary_contact = FALSE
+             WHERE confirmand_id = $1 AND guardian_id <> $2 AND is_primary_contact",
+            &[&confirmand_id, &except_guardian_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(())
+}
+
+// Links an existing guardian, or a new one created from the payload, to a confirmand. Returns the
+// guardian's ID and whether a new link was made (false if they were already linked).
+async fn insert_guardian_link(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    payload: &LinkGuardian,
+) -> Result<(i32, bool), (StatusCode, String)> {
+    let guardian_id: i32 = match (payload.guardian_id, payload.full_name.as_deref().map(str::trim)) {
+        (Some(guardian_id), _) => {
+            transaction
+                .query_opt("SELECT id FROM guardians WHERE id = $1", &[&guardian_id])
+                .await
+                .map_err(internal_error)?
+                .ok_or((StatusCode::NOT_FOUND, format!("Guardian with ID {} not found", guardian_id)))?
+                .get("id")
+        }
+        (None, Some(full_name)) if !full_name.is_empty() => {
+            transaction
+                .query_one(
+                    "INSERT INTO guardians (full_name, phone_number, email) VALUES ($1, $2, $3) RETURNING id",
+                    &[&full_name, &payload.phone_number, &payload.email],
+                )
+                .await
+                .map_err(internal_error)?
+                .get("id")
+        }
+        _ => return Err((StatusCode::BAD_REQUEST, "Either guardian_id or full_name is required".to_string())),
+    };
+
+    if payload.is_primary_contact {
+        clear_primary_contact(transaction, confirmand_id, guardian_id).await?;
+    }
+    let linked = transaction
+        .execute(
+            "INSERT INTO confirmand_guardians (confirmand_id, guardian_id, relationship, is_primary_contact, is_consent_holder)
+             VALUES ($1, $2, CAST($3 AS VARCHAR)::guardian_relationship_enum, $4, $5)
+             ON CONFLICT (confirmand_id, guardian_id) DO NOTHING",
+            &[&confirmand_id, &guardian_id, &payload.relationship.to_string(), &payload.is_primary_contact, &payload.is_consent_holder],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok((guardian_id, linked == 1))
+}
+
+// Handler for `GET /api/confirmands/:id/guardians`
+pub async fn list_guardians(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<Guardian>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = format!("{} WHERE cg.confirmand_id = $1 ORDER BY cg.is_primary_contact DESC, g.full_name", GUARDIAN_SELECT_SQL);
+    let rows = conn.query(&sql, &[&id]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Guardian::from).collect()))
+}
+
+// Handler for `POST /api/confirmands/:id/guardians`
+pub async fn link_guardian(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(mut payload): Json<LinkGuardian>,
+) -> Result<(StatusCode, Json<Guardian>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let exists = transaction.query_opt("SELECT id FROM confirmands WHERE id = $1", &[&id])
+        .await.map_err(internal_error)?;
+    if exists.is_none() {
+        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)));
+    }
+
+    let (guardian_id, linked) = insert_guardian_link(&transaction, id, &payload).await?;
+    if !linked {
+        return Err((StatusCode::CONFLICT, format!("Guardian {} is already linked to participant {}", guardian_id, id)));
+    }
+
+    let guardian = fetch_guardian(&transaction, id, guardian_id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(guardian)))
+}
+
+// Handler for `PUT /api/confirmands/:id/guardians/:guardianId`
+// Contact data belongs to the guardian, so it changes for every confirmand they are linked to.
+pub async fn update_guardian(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, guardian_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    Json(mut payload): Json<UpdateGuardian>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+
+    if payload.is_primary_contact {
+        clear_primary_contact(&transaction, id, guardian_id).await?;
+    }
+    let updated = transaction
+        .execute(
+            "UPDATE confirmand_guardians
+             SET relationship = CAST($3 AS VARCHAR)::guardian_relationship_enum,
+                 is_primary_contact = $4, is_consent_holder = $5
+             WHERE confirmand_id = $1 AND guardian_id = $2",
+            &[&id, &guardian_id, &payload.relationship.to_string(), &payload.is_primary_contact, &payload.is_consent_holder],
+        )
+        .await
+        .map_err(internal_error)?;
+    if updated == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Guardian {} is not linked to participant {}", guardian_id, id)));
+    }
+    transaction
+        .execute(
+            "UPDATE guardians SET full_name = $1, phone_number = $2, email = $3 WHERE id = $4",
+            &[&payload.full_name.trim(), &payload.phone_number, &payload.email, &guardian_id],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    ensure_minor_consent(&transaction, id).await?;
+
+    let guardian = fetch_guardian(&transaction, id, guardian_id).await?;
+    let version = current_version(&transaction, "confirmands", id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(([(header::ETAG, etag::etag(version))], Json(guardian)))
+}
+
+// Handler for `DELETE /api/confirmands/:id/guardians/:guardianId`
+pub async fn unlink_guardian_from_participant(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, guardian_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+    if unlink_guardian(&transaction, id, guardian_id).await? == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Guardian {} is not linked to participant {}", guardian_id, id)));
+    }
+    ensure_minor_consent(&transaction, id).await?;
+    let version = current_version(&transaction, "confirmands", id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok((StatusCode::NO_CONTENT, [(header::ETAG, etag::etag(version))]))
+}
+
+// ===================================================================
+// Consent Handlers
+// ===================================================================
+
+const CONSENT_SELECT_SQL: &str = "
+    SELECT co.id, co.confirmand_id, co.guardian_id, g.full_name as guardian_name, co.kind::TEXT as kind,
+           co.given_date, co.document
+    FROM consents co
+    INNER JOIN guardians g ON co.guardian_id = g.id
+";
+
+// The confirmand's age if they are a minor without a participation consent from a guardian who is
+// linked to them as consent holder.
+async fn minor_without_consent(transaction: &Transaction<'_>, confirmand_id: i32) -> Result<Option<i32>, (StatusCode, String)> {
+    let row = transaction
+        .query_one(
+            "SELECT c.birth_date, EXISTS (
+                SELECT 1 FROM consents co
+                INNER JOIN confirmand_guardians cg
+                    ON cg.confirmand_id = co.confirmand_id AND cg.guardian_id = co.guardian_id
+                WHERE co.confirmand_id = c.id AND co.kind = 'Participation' AND cg.is_consent_holder
+             ) as has_consent
+             FROM confirmands c WHERE c.id = $1",
+            &[&confirmand_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    let age = assignment::age_on(row.get("birth_date"), Utc::now().date_naive());
+    Ok((age < ADULT_AGE && !row.get::<_, bool>("has_consent")).then_some(age))
+}
+
+// Fails with a 422 field error on `guardian_consent` when the payload just written leaves a minor
+// without a participation consent.
+async fn require_guardian_consent(transaction: &Transaction<'_>, confirmand_id: i32) -> Result<(), (StatusCode, String)> {
+    let mut errors = FieldErrors::default();
+    if let Some(age) = minor_without_consent(transaction, confirmand_id).await? {
+        errors.add("guardian_consent", format!("is required: the participant is {} years old", age));
+    }
+    errors.into_result()
+}
+
+// Fails with 409 Conflict when a change to the participant's guardians or consents leaves a minor
+// without a participation consent from a guardian who is linked to them as consent holder.
+async fn ensure_minor_consent(transaction: &Transaction<'_>, confirmand_id: i32) -> Result<(), (StatusCode, String)> {
+    match minor_without_consent(transaction, confirmand_id).await? {
+        Some(age) => Err((
+            StatusCode::CONFLICT,
+            format!(
+                "Participant is {} years old; minors need a participation consent from a guardian who is consent holder",
+                age
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+// Links the guardian in `consent` as consent holder and records their participation consent.
+async fn record_guardian_consent(
+    transaction: &Transaction<'_>,
+    confirmand_id: i32,
+    consent: &GuardianConsent,
+) -> Result<(), (StatusCode, String)> {
+    let (guardian_id, _) = insert_guardian_link(transaction, confirmand_id, &consent.guardian).await?;
+    transaction
+        .execute(
+            "UPDATE confirmand_guardians SET is_consent_holder = TRUE WHERE confirmand_id = $1 AND guardian_id = $2",
+            &[&confirmand_id, &guardian_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction
+        .execute(
+            "INSERT INTO consents (confirmand_id, guardian_id, kind, given_date, document)
+             VALUES ($1, $2, CAST($3 AS VARCHAR)::consent_kind_enum, $4, $5)",
+            &[&confirmand_id, &guardian_id, &ConsentKind::Participation.to_string(), &consent.given_date, &consent.document],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(())
+}
+
+// Handler for `GET /api/confirmands/:id/consents`
+pub async fn list_consents(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<Consent>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = format!("{} WHERE co.confirmand_id = $1 ORDER BY co.given_date DESC, co.id", CONSENT_SELECT_SQL);
+    let rows = conn.query(&sql, &[&id]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Consent::from).collect()))
+}
+
+// Handler for `POST /api/confirmands/:id/consents`
+// Only a guardian linked to the confirmand as consent holder can give consent.
+pub async fn create_consent(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(mut payload): Json<CreateConsent>,
+) -> Result<(StatusCode, Json<Consent>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let holder = conn
+        .query_opt(
+            "SELECT is_consent_holder FROM confirmand_guardians WHERE confirmand_id = $1 AND guardian_id = $2",
+            &[&id, &payload.guardian_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Guardian {} is not linked to participant {}", payload.guardian_id, id)))?;
+    if !holder.get::<_, bool>("is_consent_holder") {
+        return Err((StatusCode::BAD_REQUEST, format!("Guardian {} is not a consent holder for participant {}", payload.guardian_id, id)));
+    }
+
+    let row = conn
+        .query_one(
+            "INSERT INTO consents (confirmand_id, guardian_id, kind, given_date, document)
+             VALUES ($1, $2, CAST($3 AS VARCHAR)::consent_kind_enum, $4, $5)
+             RETURNING id",
+            &[&id, &payload.guardian_id, &payload.kind.to_string(), &payload.given_date, &payload.document],
+        )
+        .await
+        .map_err(internal_error)?;
+    let consent_id: i32 = row.get("id");
+    let sql = format!("{} WHERE co.id = $1", CONSENT_SELECT_SQL);
+    let consent = conn.query_one(&sql, &[&consent_id]).await.map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(Consent::from(consent))))
+}
+
+// Handler for `DELETE /api/confirmands/:id/consents/:consentId`
+// Refused when it would leave a minor without a participation consent.
+pub async fn delete_consent(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, consent_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let expected_version = etag::if_match(&headers)?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    lock_version(&transaction, "confirmands", id, expected_version, format!("Participant with ID {} not found", id)).await?;
+    let deleted = transaction
+        .execute("DELETE FROM consents WHERE id = $1 AND confirmand_id = $2", &[&consent_id, &id])
+        .await
+        .map_err(internal_error)?;
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Consent {} not found for participant {}", consent_id, id)));
+    }
+    ensure_minor_consent(&transaction, id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ===================================================================
+// Emergency & Medical Information Handlers
+// ===================================================================
+
+// Decrypts the sensitive columns of `confirmands c` with the key in `$1`. Callers append a WHERE clause.
+const EMERGENCY_INFO_SELECT_SQL: &str = "
+    SELECT c.id,
+        pgp_sym_decrypt(c.emergency_contact_name, $1) as emergency_contact_name,
+        pgp_sym_decrypt(c.emergency_contact_phone, $1) as emergency_contact_phone,
+        pgp_sym_decrypt(c.allergies, $1) as allergies,
+        pgp_sym_decrypt(c.medical_notes, $1) as medical_notes
+    FROM confirmands c
+";
+
+// The pgcrypto key for emergency and medical data. It only ever lives in the environment.
+fn sensitive_data_key() -> Result<String, (StatusCode, String)> {
+    std::env::var("SENSITIVE_DATA_KEY").map_err(internal_error)
+}
+
+// The signed-in user's role, the catechist they are (if any) and whether their role may read
+// emergency and medical data.
+struct UserAccess {
+    role: UserRole,
+    catechist_id: Option<i32>,
+    can_view_sensitive_data: bool,
+}
+
+async fn fetch_user_access(conn: &Client, user_id: i32) -> Result<UserAccess, (StatusCode, String)> {
+    let row = conn
+        .query_opt(
+            "SELECT u.role::TEXT as role, u.catechist_id,
+                    EXISTS (SELECT 1 FROM sensitive_data_grants g WHERE g.role = u.role) as can_view_sensitive_data
+             FROM users u WHERE u.id = $1",
+            &[&user_id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::UNAUTHORIZED, "User not found".to_string()))?;
+    let role: String = row.get("role");
+    Ok(UserAccess {
+        role: UserRole::from_str(&role).map_err(internal_error)?,
+        catechist_id: row.get("catechist_id"),
+        can_view_sensitive_data: row.get("can_view_sensitive_data"),
+    })
+}
+
+fn ensure_sensitive_data_access(access: &UserAccess) -> Result<(), (StatusCode, String)> {
+    if access.can_view_sensitive_data {
+        Ok(())
+    } else {
+        Err((StatusCode::FORBIDDEN, format!("The {} role has no access to emergency and medical data", access.role)))
+    }
+}
+
+// Decrypted emergency information for several confirmands, keyed by confirmand id.
+async fn fetch_emergency_info(conn: &Client, confirmand_ids: &[i32]) -> Result<HashMap<i32, EmergencyInfo>, (StatusCode, String)> {
+    let sql = format!("{} WHERE c.id = ANY($2)", EMERGENCY_INFO_SELECT_SQL);
+    let rows = conn.query(&sql, &[&sensitive_data_key()?, &confirmand_ids]).await.map_err(internal_error)?;
+    Ok(rows.iter().map(|row| (row.get("id"), EmergencyInfo::from(row))).collect())
+}
+
+// Handler for `GET /api/confirmands/:id/emergency-info`
+pub async fn get_emergency_info(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<EmergencyInfo>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    ensure_sensitive_data_access(&fetch_user_access(&conn, user.id).await?)?;
+    let mut info = fetch_emergency_info(&conn, &[id]).await?;
+    info.remove(&id)
+        .map(Json)
+        .ok_or((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)))
+}
+
+// Handler for `PUT /api/confirmands/:id/emergency-info`
+pub async fn update_emergency_info(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<EmergencyInfo>,
+) -> Result<Json<EmergencyInfo>, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    ensure_sensitive_data_access(&fetch_user_access(&conn, user.id).await?)?;
+
+    // Blank values are stored as NULL rather than as an encrypted empty string.
+    let clean = |value: &Option<String>| value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+    let updated = conn
+        .execute(
+            "UPDATE confirmands SET
+                emergency_contact_name = pgp_sym_encrypt($2, $1),
+                emergency_contact_phone = pgp_sym_encrypt($3, $1),
+                allergies = pgp_sym_encrypt($4, $1),
+                medical_notes = pgp_sym_encrypt($5, $1)
+             WHERE id = $6",
+            &[
+                &sensitive_data_key()?,
+                &clean(&payload.emergency_contact_name),
+                &clean(&payload.emergency_contact_phone),
+                &clean(&payload.allergies),
+                &clean(&payload.medical_notes),
+                &id,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+    if updated == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Participant with ID {} not found", id)));
+    }
+    let mut info = fetch_emergency_info(&conn, &[id]).await?;
+    Ok(Json(info.remove(&id).unwrap_or_default()))
+}
+
+async fn fetch_sensitive_data_grants(conn: &Client) -> Result<SensitiveDataGrants, (StatusCode, String)> {
+    let rows = conn
+        .query("SELECT role::TEXT as role FROM sensitive_data_grants ORDER BY role", &[])
+        .await
+        .map_err(internal_error)?;
+    let roles = rows
+        .iter()
+        .map(|row| UserRole::from_str(&row.get::<_, String>("role")).map_err(internal_error))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(SensitiveDataGrants { roles })
+}
+
+// Handler for `GET /api/sensitive-data/grants`
+pub async fn get_sensitive_data_grants(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<SensitiveDataGrants>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_sensitive_data_grants(&conn).await?))
+}
+
+// Handler for `PUT /api/sensitive-data/grants`
+// Replaces the whole list of roles with access. Only administrators may change it.
+pub async fn update_sensitive_data_grants(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(payload): Json<SensitiveDataGrants>,
+) -> Result<Json<SensitiveDataGrants>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    if fetch_user_access(&conn, user.id).await?.role != UserRole::Admin {
+        return Err((StatusCode::FORBIDDEN, "Only administrators can grant access to sensitive data".to_string()));
+    }
+    let roles: Vec<String> = payload.roles.iter().map(|role| role.to_string()).collect();
+
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    transaction
+        .execute("DELETE FROM sensitive_data_grants WHERE NOT (role::TEXT = ANY($1))", &[&roles])
+        .await
+        .map_err(internal_error)?;
+    transaction
+        .execute(
+            "INSERT INTO sensitive_data_grants (role)
+             SELECT CAST(r AS VARCHAR)::user_role_enum FROM UNNEST($1::TEXT[]) r
+             ON CONFLICT (role) DO NOTHING",
+            &[&roles],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+
+    Ok(Json(fetch_sensitive_data_grants(&conn).await?))
+}
+
+// ===================================================================
+// User Account Handlers
+// ===================================================================
+
+const USER_ACCOUNT_SELECT_SQL: &str = "SELECT id, username, role::TEXT as role, catechist_id FROM users";
+
+fn user_account(row: &Row) -> Result<UserAccount, (StatusCode, String)> {
+    Ok(UserAccount {
+        id: row.get("id"),
+        username: row.get("username"),
+        role: UserRole::from_str(&row.get::<_, String>("role")).map_err(internal_error)?,
+        catechist_id: row.get("catechist_id"),
+    })
+}
+
+// Handler for `GET /api/users`
+// Administrators only.
+pub async fn list_users(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<UserAccount>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    if fetch_user_access(&conn, user.id).await?.role != UserRole::Admin {
+        return Err((StatusCode::FORBIDDEN, "Only administrators can list user accounts".to_string()));
+    }
+    let sql = format!("{} ORDER BY username", USER_ACCOUNT_SELECT_SQL);
+    let rows = conn.query(&sql, &[]).await.map_err(internal_error)?;
+    Ok(Json(rows.iter().map(user_account).collect::<Result<Vec<_>, _>>()?))
+}
+
+// Handler for `PUT /api/users/:id/access`
+// Sets an account's role and the catechist it belongs to. Administrators only, and the last
+// administrator cannot be demoted.
+pub async fn update_user_access(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateUserAccess>,
+) -> Result<Json<UserAccount>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    if fetch_user_access(&conn, user.id).await?.role != UserRole::Admin {
+        return Err((StatusCode::FORBIDDEN, "Only administrators can change roles".to_string()));
+    }
+
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    // Locking the administrators keeps two demotions from each counting on the other.
+    let admins: Vec<i32> = transaction
+        .query("SELECT id FROM users WHERE role = 'Admin' FOR UPDATE", &[])
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    if payload.role != UserRole::Admin && admins == [id] {
+        return Err((StatusCode::CONFLICT, "The last administrator cannot be given another role".to_string()));
+    }
+    if let Some(catechist_id) = payload.catechist_id {
+        let exists = transaction
+            .query_opt("SELECT 1 FROM catechists WHERE id = $1", &[&catechist_id])
+            .await
+            .map_err(internal_error)?;
+        if exists.is_none() {
+            return Err((StatusCode::BAD_REQUEST, format!("Catechist with ID {} not found", catechist_id)));
+        }
+    }
+
+    let sql = "
+        UPDATE users SET role = CAST($1 AS VARCHAR)::user_role_enum, catechist_id = $2
+        WHERE id = $3
+        RETURNING id, username, role::TEXT as role, catechist_id
+    ";
+    let row = transaction
+        .query_opt(sql, &[&payload.role.to_string(), &payload.catechist_id, &id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("User with ID {} not found", id)))?;
+    let account = user_account(&row)?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(Json(account))
+}
+
+// ===================================================================
+// Household Handlers
+// ===================================================================
+
+// Callers append a WHERE clause followed by `GROUP BY h.id`.
+const HOUSEHOLD_SELECT_SQL: &str = "
+    SELECT h.id, h.name, h.address, h.phone_number, h.email, COUNT(c.id) as confirmand_count
+    FROM households h
+    LEFT JOIN confirmands c ON c.household_id = h.id AND c.archived_at IS NULL
+";
+
+async fn fetch_household_details(conn: &impl deadpool_postgres::GenericClient, id: i32) -> Result<HouseholdDetails, (StatusCode, String)> {
+    let sql = format!("{} WHERE h.id = $1 GROUP BY h.id", HOUSEHOLD_SELECT_SQL);
+    let household = conn
+        .query_opt(&sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .map(Household::from)
+        .ok_or((StatusCode::NOT_FOUND, format!("Household with ID {} not found", id)))?;
+    let confirmand_rows = conn
+        .query(
+            "SELECT id, full_name, phone_number, email FROM confirmands
+             WHERE household_id = $1 AND archived_at IS NULL
+             ORDER BY birth_date",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?;
+    let guardian_rows = conn
+        .query(
+            "SELECT id, full_name, phone_number, email FROM guardians WHERE household_id = $1 ORDER BY full_name",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(HouseholdDetails {
+        household,
+        confirmands: confirmand_rows.into_iter().map(HouseholdMember::from).collect(),
+        guardians: guardian_rows.into_iter().map(HouseholdMember::from).collect(),
+    })
+}
+
+// Handler for `GET /api/households`
+pub async fn list_households(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Household>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = format!("{} GROUP BY h.id ORDER BY h.name", HOUSEHOLD_SELECT_SQL);
+    let rows = conn.query(&sql, &[]).await.map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Household::from).collect()))
+}
+
+// Handler for `POST /api/households`
+pub async fn create_household(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateHousehold>,
+) -> Result<(StatusCode, Json<HouseholdDetails>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_one(
+            "INSERT INTO households (name, address, phone_number, email) VALUES ($1, $2, $3, $4) RETURNING id",
+            &[&payload.name.trim(), &payload.address, &payload.phone_number, &payload.email],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(fetch_household_details(&conn, row.get("id")).await?)))
+}
+
+// Handler for `GET /api/households/:id`
+pub async fn get_household_details(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<HouseholdDetails>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_household_details(&conn, id).await?))
+}
+
+// Handler for `PUT /api/households/:id`
+// The household address is the family's address, so it is copied to every confirmand in it.
+pub async fn update_household(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(mut payload): Json<CreateHousehold>,
+) -> Result<Json<HouseholdDetails>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let updated = transaction
+        .execute(
+            "UPDATE households SET name = $1, address = $2, phone_number = $3, email = $4 WHERE id = $5",
+            &[&payload.name.trim(), &payload.address, &payload.phone_number, &payload.email, &id],
+        )
+        .await
+        .map_err(internal_error)?;
+    if updated == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Household with ID {} not found", id)));
+    }
+    if let Some(address) = &payload.address {
+        transaction
+            .execute("UPDATE confirmands SET address = $1 WHERE household_id = $2", &[address, &id])
+            .await
+            .map_err(internal_error)?;
+    }
+    let details = fetch_household_details(&transaction, id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(Json(details))
+}
+
+// Handler for `DELETE /api/households/:id`
+// Only the household goes; its confirmands and guardians are kept.
+pub async fn delete_household(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let deleted = conn
+        .execute("DELETE FROM households WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Household with ID {} not found", id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `POST /api/households/:id/members`
+// Confirmands move out of any previous household. Their guardians who are not in a household yet
+// come along, and take the household address if it has one.
+pub async fn add_household_members(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<AddHouseholdMembers>,
+) -> Result<Json<HouseholdDetails>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    let household = transaction
+        .query_opt("SELECT address FROM households WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Household with ID {} not found", id)))?;
+    let address: Option<String> = household.get("address");
+
+    let moved = transaction
+        .execute(
+            "UPDATE confirmands SET household_id = $1, address = COALESCE($2, address) WHERE id = ANY($3)",
+            &[&id, &address, &payload.confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    if moved as usize != payload.confirmand_ids.len() {
+        return Err((StatusCode::BAD_REQUEST, "Some participants do not exist".to_string()));
+    }
+    let linked = transaction
+        .execute("UPDATE guardians SET household_id = $1 WHERE id = ANY($2)", &[&id, &payload.guardian_ids])
+        .await
+        .map_err(internal_error)?;
+    if linked as usize != payload.guardian_ids.len() {
+        return Err((StatusCode::BAD_REQUEST, "Some guardians do not exist".to_string()));
+    }
+    transaction
+        .execute(
+            "UPDATE guardians g SET household_id = $1
+             FROM confirmand_guardians cg
+             WHERE cg.guardian_id = g.id AND cg.confirmand_id = ANY($2) AND g.household_id IS NULL",
+            &[&id, &payload.confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let details = fetch_household_details(&transaction, id).await?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(Json(details))
+}
+
+// Handler for `DELETE /api/households/:id/confirmands/:confirmandId`
+pub async fn remove_household_confirmand(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, confirmand_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let removed = conn
+        .execute(
+            "UPDATE confirmands SET household_id = NULL WHERE id = $1 AND household_id = $2",
+            &[&confirmand_id, &id],
+        )
+        .await
+        .map_err(internal_error)?;
+    if removed == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Participant {} is not in household {}", confirmand_id, id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `DELETE /api/households/:id/guardians/:guardianId`
+pub async fn remove_household_guardian(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, guardian_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let conn = connect_as(&state, &user).await?;
+    let removed = conn
+        .execute(
+            "UPDATE guardians SET household_id = NULL WHERE id = $1 AND household_id = $2",
+            &[&guardian_id, &id],
+        )
+        .await
+        .map_err(internal_error)?;
+    if removed == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Guardian {} is not in household {}", guardian_id, id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `GET /api/groups/:id/contacts`
+// One contact per family: the household's own phone and email, falling back to the primary
+// contact among the guardians. Confirmands without a household are reached directly.
+pub async fn get_group_family_contacts(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<FamilyContact>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let sql = "
+        WITH members AS (
+            SELECT c.id, c.full_name, c.phone_number, c.email, c.household_id
+            FROM confirmands c
+            INNER JOIN confirmand_confirmation_groups ccg ON c.id = ccg.confirmand_id
+            WHERE ccg.confirmation_group_id = $1 AND c.archived_at IS NULL
+        ),
+        primary_contacts AS (
+            SELECT DISTINCT ON (m.household_id) m.household_id, g.full_name, g.phone_number, g.email
+            FROM members m
+            INNER JOIN confirmand_guardians cg ON cg.confirmand_id = m.id AND cg.is_primary_contact
+            INNER JOIN guardians g ON cg.guardian_id = g.id
+            WHERE m.household_id IS NOT NULL
+            ORDER BY m.household_id, g.id
+        )
+        SELECT
+            h.id as household_id, h.name,
+            ARRAY_AGG(m.full_name ORDER BY m.full_name) as confirmand_names,
+            MIN(pc.full_name) as contact_name,
+            COALESCE(h.phone_number, MIN(pc.phone_number)) as phone_number,
+            COALESCE(h.email, MIN(pc.email)) as email
+        FROM members m
+        INNER JOIN households h ON m.household_id = h.id
+        LEFT JOIN primary_contacts pc ON pc.household_id = h.id
+        GROUP BY h.id
+        UNION ALL
+        SELECT NULL, m.full_name, ARRAY[m.full_name], m.full_name, m.phone_number, m.email
+        FROM members m
+        WHERE m.household_id IS NULL
+        ORDER BY name
+    ";
+    let rows = conn.query(sql, &[&id]).await.map_err(internal_error)?;
+    let contacts = rows
+        .into_iter()
+        .map(|row| FamilyContact {
+            household_id: row.get("household_id"),
+            name: row.get("name"),
+            confirmand_names: row.get("confirmand_names"),
+            contact_name: row.get("contact_name"),
+            phone_number: row.get("phone_number"),
+            email: row.get("email"),
+        })
+        .collect();
+    Ok(Json(contacts))
+}
+
+// ===================================================================
+// Confirmation Ceremony Handlers
+// ===================================================================
+
+const CEREMONY_SELECT_SQL: &str = "
+    SELECT
+        ce.id, ce.ceremony_date, ce.church, ce.presiding_bishop, ce.finalized_at,
+        (SELECT COUNT(*) FROM ceremony_candidates cc WHERE cc.ceremony_id = ce.id) as candidate_count
+    FROM ceremonies ce
+";
+
+async fn fetch_ceremony_details(conn: &Client, id: i32) -> Result<CeremonyDetails, (StatusCode, String)> {
+    let row = conn
+        .query_opt(&format!("{} WHERE ce.id = $1", CEREMONY_SELECT_SQL), &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Ceremony with ID {} not found", id)))?;
+    let candidate_rows = conn
+        .query(
+            "SELECT c.id as confirmand_id, c.full_name, c.birth_date, sp.full_name as sponsor_name, cc.attended
+             FROM ceremony_candidates cc
+             INNER JOIN confirmands c ON cc.confirmand_id = c.id
+             LEFT JOIN sponsors sp ON sp.confirmand_id = c.id
+             WHERE cc.ceremony_id = $1
+             ORDER BY c.full_name",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(CeremonyDetails {
+        ceremony: Ceremony::from(row),
+        candidates: candidate_rows.into_iter().map(CeremonyCandidate::from).collect(),
+    })
+}
+
+// Returns 409 if the ceremony has already been finalized; its candidate list is then frozen.
+// Inside a transaction, the ceremony stays locked until it commits.
+async fn ensure_ceremony_is_open(conn: &impl deadpool_postgres::GenericClient, id: i32) -> Result<(), (StatusCode, String)> {
+    let row = conn
+        .query_opt("SELECT finalized_at FROM ceremonies WHERE id = $1 FOR UPDATE", &[&id])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Ceremony with ID {} not found", id)))?;
+    let finalized_at: Option<chrono::DateTime<Utc>> = row.get("finalized_at");
+    if finalized_at.is_some() {
+        return Err((StatusCode::CONFLICT, format!("Ceremony {} has already been finalized", id)));
+    }
+    Ok(())
+}
+
+// Participants who may be put on a ceremony's candidate list: not archived, not yet confirmed and not already on an
+// open ceremony's list. Someone who missed a finalized ceremony may be scheduled again. Callers append
+// `AND ...` / `ORDER BY`.
+const SCHEDULABLE_CONFIRMANDS_SQL: &str = "
+    SELECT c.id
+    FROM confirmands c
+    WHERE c.archived_at IS NULL
+      AND NOT EXISTS (
+            SELECT 1 FROM ceremony_candidates cc
+            INNER JOIN ceremonies ce ON cc.ceremony_id = ce.id
+            WHERE cc.confirmand_id = c.id AND (ce.finalized_at IS NULL OR cc.attended)
+        )
+      AND NOT EXISTS (
+            SELECT 1 FROM confirmand_sacraments cs
+            INNER JOIN sacraments s ON cs.sacrament_id = s.id
+            WHERE cs.confirmand_id = c.id AND s.code = 'confirmation'
+        )
+";
+
+// Schedulable participants (see above) who meet every requirement.
+async fn fetch_eligible_candidates(conn: &impl deadpool_postgres::GenericClient) -> Result<Vec<EligibilityReport>, (StatusCode, String)> {
+    let ids: Vec<i32> = conn
+        .query(SCHEDULABLE_CONFIRMANDS_SQL, &[])
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    let reports = fetch_eligibility_reports(conn, &ids).await?;
+    Ok(reports.into_iter().filter(|report| report.eligible).collect())
+}
+
+// Handler for `GET /api/ceremonies`
+pub async fn list_ceremonies(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Ceremony>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let rows = conn
+        .query(&format!("{} ORDER BY ce.ceremony_date DESC", CEREMONY_SELECT_SQL), &[])
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(Ceremony::from).collect()))
+}
+
+// Handler for `POST /api/ceremonies`
+pub async fn create_ceremony(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CreateCeremony>,
+) -> Result<(StatusCode, Json<Ceremony>), (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_one(
+            "INSERT INTO ceremonies (ceremony_date, church, presiding_bishop) VALUES ($1, $2, $3)
+             RETURNING id, ceremony_date, church, presiding_bishop, finalized_at, 0::BIGINT as candidate_count",
+            &[&payload.ceremony_date, &payload.church.trim(), &payload.presiding_bishop.trim()],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok((StatusCode::CREATED, Json(Ceremony::from(row))))
+}
+
+// Handler for `GET /api/ceremonies/:id`
+pub async fn get_ceremony_details(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<CeremonyDetails>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_ceremony_details(&conn, id).await?))
+}
+
+// Handler for `DELETE /api/ceremonies/:id`
+// Only ceremonies that have not been finalized can be deleted.
+pub async fn delete_ceremony(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    ensure_ceremony_is_open(&transaction, id).await?;
+    transaction.execute("DELETE FROM ceremonies WHERE id = $1", &[&id]).await.map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `GET /api/ceremonies/eligible-candidates`
+pub async fn list_eligible_ceremony_candidates(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<EligibilityReport>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_eligible_candidates(&conn).await?))
+}
+
+// Handler for `POST /api/ceremonies/:id/candidates`
+// Every confirmand must currently be eligible and not be a candidate for another ceremony
+// (having missed a finalized one is fine).
+pub async fn add_ceremony_candidates(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<AddCeremonyCandidates>,
+) -> Result<Json<CeremonyDetails>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    ensure_ceremony_is_open(&transaction, id).await?;
+    // Locked so that a concurrent request cannot put the same people on another ceremony meanwhile.
+    transaction
+        .execute("SELECT id FROM confirmands WHERE id = ANY($1) FOR UPDATE", &[&payload.confirmand_ids])
+        .await
+        .map_err(internal_error)?;
+
+    let already_listed: HashSet<i32> = transaction
+        .query(
+            "SELECT confirmand_id FROM ceremony_candidates WHERE ceremony_id = $1 AND confirmand_id = ANY($2)",
+            &[&id, &payload.confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("confirmand_id"))
+        .collect();
+    let mut new_ids: Vec<i32> = payload.confirmand_ids.iter().copied().filter(|c| !already_listed.contains(c)).collect();
+    new_ids.sort_unstable();
+    new_ids.dedup();
+
+    let schedulable: HashSet<i32> = transaction
+        .query(&format!("{} AND c.id = ANY($1)", SCHEDULABLE_CONFIRMANDS_SQL), &[&new_ids])
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    let taken: HashMap<i32, i32> = transaction
+        .query(
+            "SELECT cc.confirmand_id, cc.ceremony_id
+             FROM ceremony_candidates cc
+             INNER JOIN ceremonies ce ON cc.ceremony_id = ce.id
+             WHERE cc.confirmand_id = ANY($1) AND (ce.finalized_at IS NULL OR cc.attended)",
+            &[&new_ids],
+        )
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| (row.get("confirmand_id"), row.get("ceremony_id")))
+        .collect();
+    let reports = fetch_eligibility_reports(&transaction, &new_ids).await?;
+
+    let mut problems: Vec<String> = new_ids
+        .iter()
+        .filter(|c| !reports.iter().any(|report| report.confirmand_id == **c))
+        .map(|c| format!("Participant with ID {} not found", c))
+        .collect();
+    for report in &reports {
+        if let Some(ceremony_id) = taken.get(&report.confirmand_id) {
+            problems.push(format!("{} is already a candidate for ceremony {}", report.full_name, ceremony_id));
+        } else if !schedulable.contains(&report.confirmand_id) {
+            problems.push(format!("{} is already confirmed", report.full_name));
+        } else if !report.eligible {
+            let unmet: Vec<&str> = report
+                .requirements
+                .iter()
+                .filter(|r| r.status != RequirementStatus::Met)
+                .map(|r| r.label.as_str())
+                .collect();
+            problems.push(format!("{} is not eligible ({})", report.full_name, unmet.join(", ")));
+        }
+    }
+    if !problems.is_empty() {
+        return Err((StatusCode::CONFLICT, problems.join("; ")));
+    }
+
+    for confirmand_id in &new_ids {
+        transaction
+            .execute(
+                "INSERT INTO ceremony_candidates (ceremony_id, confirmand_id) VALUES ($1, $2)",
+                &[&id, confirmand_id],
+            )
+            .await
+            .map_err(internal_error)?;
+    }
+    transaction.commit().await.map_err(internal_error)?;
+
+    Ok(Json(fetch_ceremony_details(&conn, id).await?))
+}
+
+// Handler for `DELETE /api/ceremonies/:id/candidates/:confirmandId`
+pub async fn remove_ceremony_candidate(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, confirmand_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+    ensure_ceremony_is_open(&transaction, id).await?;
+    let deleted = transaction
+        .execute(
+            "DELETE FROM ceremony_candidates WHERE ceremony_id = $1 AND confirmand_id = $2",
+            &[&id, &confirmand_id],
+        )
+        .await
+        .map_err(internal_error)?;
+    if deleted == 0 {
+        return Err((StatusCode::NOT_FOUND, format!("Participant {} is not a candidate for ceremony {}", confirmand_id, id)));
+    }
+    transaction.commit().await.map_err(internal_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for `POST /api/ceremonies/:id/finalize`
+// Records the Confirmation sacrament for every candidate who attended, using the ceremony's date,
+// church and presiding bishop, and freezes the candidate list.
+pub async fn finalize_ceremony(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<FinalizeCeremony>,
+) -> Result<Json<CeremonyDetails>, (StatusCode, String)> {
+    let mut conn = connect_as(&state, &user).await?;
+    let transaction = conn.transaction().await.map_err(internal_error)?;
+
+    let ceremony = transaction
+        .query_opt(
+            "SELECT ceremony_date, church, presiding_bishop, finalized_at FROM ceremonies WHERE id = $1 FOR UPDATE",
+            &[&id],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("Ceremony with ID {} not found", id)))?;
+    let finalized_at: Option<chrono::DateTime<Utc>> = ceremony.get("finalized_at");
+    if finalized_at.is_some() {
+        return Err((StatusCode::CONFLICT, format!("Ceremony {} has already been finalized", id)));
+    }
+    let ceremony_date: NaiveDate = ceremony.get("ceremony_date");
+    let church: String = ceremony.get("church");
+    let presiding_bishop: String = ceremony.get("presiding_bishop");
+
+    let sacrament_id: i16 = transaction
+        .query_opt("SELECT id FROM sacraments WHERE code = 'confirmation'", &[])
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::CONFLICT, "No sacrament is marked as Confirmation".to_string()))?
+        .get("id");
+
+    // A mistyped id would otherwise silently record the Confirmation of someone who was not there.
+    let candidate_ids: Vec<i32> = transaction
+        .query("SELECT confirmand_id FROM ceremony_candidates WHERE ceremony_id = $1", &[&id])
+        .await
+        .map_err(internal_error)?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    let unknown: Vec<String> = payload
+        .absent_confirmand_ids
+        .iter()
+        .filter(|absent| !candidate_ids.contains(absent))
+        .map(i32::to_string)
+        .collect();
+    if !unknown.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Not candidates for ceremony {}: {}", id, unknown.join(", ")),
+        ));
+    }
+
+    transaction
+        .execute(
+            "UPDATE ceremony_candidates SET attended = NOT (confirmand_id = ANY($2)) WHERE ceremony_id = $1",
+            &[&id, &payload.absent_confirmand_ids],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction
+        .execute(
+            "INSERT INTO confirmand_sacraments (confirmand_id, sacrament_id, received_date, church, officiant)
+             SELECT confirmand_id, $2, $3, $4, $5
+             FROM ceremony_candidates
+             WHERE ceremony_id = $1 AND attended
+             ON CONFLICT DO NOTHING",
+            &[&id, &sacrament_id, &ceremony_date, &church, &presiding_bishop],
+        )
+        .await
+        .map_err(internal_error)?;
+    transaction
+        .execute("UPDATE ceremonies SET finalized_at = NOW() WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?;
+    transaction.commit().await.map_err(internal_error)?;
+
+    Ok(Json(fetch_ceremony_details(&conn, id).await?))
+}
+
+// ===================================================================
+// Certificate Handlers
+// ===================================================================
+
+async fn fetch_certificate_settings(conn: &Client) -> Result<CertificateSettings, (StatusCode, String)> {
+    let row = conn
+        .query_one(
+            "SELECT parish_name, diocese, address, signatory_name, signatory_title FROM certificate_settings",
+            &[],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(CertificateSettings::from(row))
+}
+
+async fn fetch_certificate_template(conn: &Client, kind: &CertificateKind) -> Result<CertificateTemplate, (StatusCode, String)> {
+    let row = conn
+        .query_opt(
+            "SELECT kind::TEXT as kind, title, body FROM certificate_templates WHERE kind = CAST($1 AS VARCHAR)::certificate_kind_enum",
+            &[&kind.to_string()],
+        )
+        .await
+        .map_err(internal_error)?
+        .ok_or((StatusCode::NOT_FOUND, format!("No template for {} certificates", kind)))?;
+    Ok(CertificateTemplate::from(row))
+}
+
+// Parses the `:file` path segment, e.g. `confirmation.pdf`.
+fn parse_certificate_file(file: &str, extension: &str) -> Result<CertificateKind, (StatusCode, String)> {
+    file.strip_suffix(extension)
+        .and_then(|kind| CertificateKind::from_str(kind).ok())
+        .ok_or((StatusCode::NOT_FOUND, format!("Unknown certificate '{}'", file)))
+}
+
+// Handler for `GET /api/certificates/settings`
+pub async fn get_certificate_settings(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<CertificateSettings>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    Ok(Json(fetch_certificate_settings(&conn).await?))
+}
+
+// Handler for `PUT /api/certificates/settings`
+pub async fn update_certificate_settings(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(mut payload): Json<CertificateSettings>,
+) -> Result<Json<CertificateSettings>, (StatusCode, String)> {
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_one(
+            "INSERT INTO certificate_settings (id, parish_name, diocese, address, signatory_name, signatory_title)
+             VALUES (TRUE, $1, $2, $3, $4, $5)
+             ON CONFLICT (id) DO UPDATE SET
+                parish_name = EXCLUDED.parish_name, diocese = EXCLUDED.diocese, address = EXCLUDED.address,
+                signatory_name = EXCLUDED.signatory_name, signatory_title = EXCLUDED.signatory_title
+             RETURNING parish_name, diocese, address, signatory_name, signatory_title",
+            &[
+                &payload.parish_name.trim(),
+                &payload.diocese,
+                &payload.address,
+                &payload.signatory_name,
+                &payload.signatory_title,
+            ],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(CertificateSettings::from(row)))
+}
+
+// Handler for `GET /api/certificates/templates`
+pub async fn list_certificate_templates(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<Json<Vec<CertificateTemplate>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let rows = conn
+        .query("SELECT kind::TEXT as kind, title, body FROM certificate_templates ORDER BY kind", &[])
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(CertificateTemplate::from).collect()))
+}
+
+// Handler for `PUT /api/certificates/templates/:kind`
+pub async fn update_certificate_template(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(kind): Path<String>,
+    Json(mut payload): Json<UpdateCertificateTemplate>,
+) -> Result<Json<CertificateTemplate>, (StatusCode, String)> {
+    let kind = CertificateKind::from_str(&kind)
+        .map_err(|_| (StatusCode::NOT_FOUND, format!("Unknown certificate kind '{}'", kind)))?;
+    payload.validate(Utc::now().date_naive())?;
+    let conn = connect_as(&state, &user).await?;
+    let row = conn
+        .query_one(
+            "INSERT INTO certificate_templates (kind, title, body)
+             VALUES (CAST($1 AS VARCHAR)::certificate_kind_enum, $2, $3)
+             ON CONFLICT (kind) DO UPDATE SET title = EXCLUDED.title, body = EXCLUDED.body
+             RETURNING kind::TEXT as kind, title, body",
+            &[&kind.to_string(), &payload.title.trim(), &payload.body],
+        )
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(CertificateTemplate::from(row)))
+}
+
+// Handler for `GET /api/confirmands/:id/certificates/:kind.pdf`
+pub async fn get_confirmand_certificate(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, file)): Path<(i32, String)>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let kind = parse_certificate_file(&file, ".pdf")?;
+    let conn = state.get().await.map_err(internal_error)?;
+    let settings = fetch_certificate_settings(&conn).await?;
+    let template = fetch_certificate_template(&conn, &kind).await?;
+    let details = fetch_participant_details(&conn, id).await?;
+
+    let values = certificates::placeholder_values(&kind, &details, &settings, Utc::now().date_naive())
+        .map_err(|reason| (StatusCode::CONFLICT, reason))?;
+    let pdf = certificates::render(&settings, &template, &values).map_err(internal_error)?;
+    let disposition = format!(
+        "attachment; filename=\"{}\"",
+        certificates::file_name(id, &details.confirmand.full_name, &kind)
+    );
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/pdf".to_string()), (header::CONTENT_DISPOSITION, disposition)],
+        pdf,
+    ))
+}
+
+// Handler for `GET /api/groups/:id/certificates/:kind.zip`
+// One certificate per member; members who do not qualify for this kind are left out.
+pub async fn get_group_certificates(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((group_id, file)): Path<(i32, String)>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let kind = parse_certificate_file(&file, ".zip")?;
+    let conn = state.get().await.map_err(internal_error)?;
+    let settings = fetch_certificate_settings(&conn).await?;
+    let template = fetch_certificate_template(&conn, &kind).await?;
+    // Archived participants, including duplicates merged into another record, get no certificate.
+    let member_rows = conn
+        .query(
+            "SELECT ccg.confirmand_id FROM confirmand_confirmation_groups ccg
+             INNER JOIN confirmands c ON ccg.confirmand_id = c.id
+             WHERE ccg.confirmation_group_id = $1 AND c.archived_at IS NULL",
+            &[&group_id],
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let today = Utc::now().date_naive();
+    let mut files = Vec::new();
+    for row in member_rows {
+        let details = fetch_participant_details(&conn, row.get("confirmand_id")).await?;
+        let Ok(values) = certificates::placeholder_values(&kind, &details, &settings, today) else {
+            continue;
+        };
+        let pdf = certificates::render(&settings, &template, &values).map_err(internal_error)?;
+        files.push((certificates::file_name(details.confirmand.id, &details.confirmand.full_name, &kind), pdf));
+    }
+    if files.is_empty() {
+        return Err((StatusCode::CONFLICT, format!("No member of group {} qualifies for a {} certificate", group_id, kind)));
+    }
+
+    let archive = certificates::zip_files(files).map_err(internal_error)?;
+    let disposition = format!("attachment; filename=\"group-{}-{}-certificates.zip\"", group_id, kind);
+    Ok((
+        [(header::CONTENT_TYPE, "application/zip".to_string()), (header::CONTENT_DISPOSITION, disposition)],
+        archive,
+    ))
+}
+
+// ===================================================================
+// Group Roster Handlers
+// ===================================================================
+
+// Handler for `GET /api/groups/:id/roster.pdf` and `GET /api/groups/:id/roster.csv`
+// The PDF ends with a blank attendance sheet for the group's upcoming sessions.
+pub async fn get_group_roster(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((id, file)): Path<(i32, String)>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let group = fetch_group_details(&conn, id).await?;
+
+    // Emergency contacts and allergies are only printed for the group's own catechist, and only
+    // if their role has been granted access to that data.
+    let access = fetch_user_access(&conn, user.id).await?;
+    let group_catechist: Option<i32> = conn
+        .query_one("SELECT catechist_id FROM confirmation_groups WHERE id = $1", &[&id])
+        .await
+        .map_err(internal_error)?
+        .get("catechist_id");
+    let emergency = if access.can_view_sensitive_data && access.catechist_id.is_some() && access.catechist_id == group_catechist {
+        let member_ids: Vec<i32> = group.members.iter().map(|m| m.id).collect();
+        Some(fetch_emergency_info(&conn, &member_ids).await?)
+    } else {
+        None
+    };
+
+    let (content_type, body) = match file.as_str() {
+        "roster.csv" => (
+            "text/csv; charset=utf-8",
+            roster::to_csv(&group.members, emergency.as_ref()).map_err(internal_error)?,
+        ),
+        "roster.pdf" => {
+            let settings = fetch_certificate_settings(&conn).await?;
+            let today = Utc::now().date_naive();
+            let upcoming: Vec<NaiveDate> = fetch_group_schedule(&conn, id)
+                .await?
+                .sessions
+                .into_iter()
+                .filter(|date| *date >= today)
+                .collect();
+            let pdf = roster::render(&settings.parish_name, &group, emergency.as_ref(), &upcoming).map_err(internal_error)?;
+            ("application/pdf", pdf)
+        }
+        _ => return Err((StatusCode::NOT_FOUND, format!("Unknown roster format '{}'", file))),
+    };
+
+    let extension = file.rsplit('.').next().unwrap_or_default();
+    let disposition = format!("attachment; filename=\"group-{}-module-{}-roster.{}\"", id, group.module, extension);
+    Ok((
+        [(header::CONTENT_TYPE, content_type.to_string()), (header::CONTENT_DISPOSITION, disposition)],
+        body,
+    ))
+}
+
+// ===================================================================
+// Audit Log Handlers
+// ===================================================================
+
+// A pooled connection tagged with the signed-in user, so the audit triggers know who made each
+// change. Every handler that writes uses this instead of `state.get()`; the pool clears the tag
+// when the connection is returned (see `db::create_pool`).
+async fn connect_as(state: &AppState, user: &AuthenticatedUser) -> Result<deadpool_postgres::Object, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    conn.execute("SELECT set_config('crisma.user_id', $1, false)", &[&user.id.to_string()])
+        .await
+        .map_err(internal_error)?;
+    Ok(conn)
+}
+
+const AUDIT_LOG_LIMIT: i64 = 500;
+
+// Callers append WHERE / ORDER BY clauses.
+const AUDIT_SELECT_SQL: &str = "
+    SELECT a.id, a.user_id, u.username, a.entity, a.entity_id, a.confirmand_id,
+           a.action::TEXT as action, a.before, a.after, a.changed_at
+    FROM audit_log a
+    LEFT JOIN users u ON a.user_id = u.id
+";
+
+// Handler for `GET /api/audit?entity=confirmand&id=12`
+// The most recent entries first, filtered by entity and id when given. For a confirmand, that is
+// everything recorded against the participant: their own row, sacraments, guardians, enrollments...
+// Administrators only.
+pub async fn list_audit_entries(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(query): Query<AuditQuery>,
+) -> Result<Json<Vec<AuditEntry>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    if fetch_user_access(&conn, user.id).await?.role != UserRole::Admin {
+        return Err((StatusCode::FORBIDDEN, "Only administrators can read the audit log".to_string()));
+    }
+    let rows = match (query.entity.as_deref(), query.id) {
+        (Some("confirmand"), Some(id)) => {
+            let sql = format!(
+                "{} WHERE a.confirmand_id = $1 ORDER BY a.changed_at DESC, a.id DESC LIMIT $2",
+                AUDIT_SELECT_SQL
+            );
+            conn.query(&sql, &[&id, &AUDIT_LOG_LIMIT]).await
+        }
+        _ => {
+            let sql = format!(
+                "{} WHERE ($1::TEXT IS NULL OR a.entity = $1) AND ($2::INT IS NULL OR a.entity_id = $2)
+                 ORDER BY a.changed_at DESC, a.id DESC
+                 LIMIT $3",
+                AUDIT_SELECT_SQL
+            );
+            conn.query(&sql, &[&query.entity, &query.id, &AUDIT_LOG_LIMIT]).await
+        }
+    }
+    .map_err(internal_error)?;
+    Ok(Json(rows.into_iter().map(AuditEntry::from).collect()))
+}
+
+// Handler for `GET /api/confirmands/:id/history`
+// A readable timeline for the participant page: edits, group enrollments and transfers, completed
+// modules and sacraments, oldest first.
+pub async fn get_confirmand_history(
+    _user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<HistoryEvent>>, (StatusCode, String)> {
+    let conn = state.get().await.map_err(internal_error)?;
+    let details = fetch_participant_details(&conn, id).await?;
+    let sql = format!(
+        "{} WHERE a.confirmand_id = $1 AND a.entity IN ('confirmand', 'enrollment', 'sacrament_record')
+         ORDER BY a.changed_at, a.id",
+        AUDIT_SELECT_SQL
+    );
+    let audit: Vec<AuditEntry> = conn
+        .query(&sql, &[&id])
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(AuditEntry::from)
+        .collect();
+    Ok(Json(history::build(&details, &audit, Utc::now().date_naive())))
+}
+
 pub async fn me_handler(
     user: AuthenticatedUser, // PROTECTED
     State(state): State<AppState>,
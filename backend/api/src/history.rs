@@ -0,0 +1,232 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use crate::models::{
+    AuditAction, AuditEntry, ConfirmandDetails, FieldChange, GroupSummary, HistoryEvent, HistoryEventKind,
+};
+
+// Columns of `confirmands` that change without anyone editing the participant.
+const IGNORED_FIELDS: &[&str] = &["id", "creation_date"];
+
+// Builds a participant's timeline, oldest first, from their details and the audit entries recorded
+// against them. There is no per-session attendance register, so the attendance milestones are the
+// modules completed, i.e. groups that have ended (the same rule the eligibility check uses).
+pub fn build(details: &ConfirmandDetails, audit: &[AuditEntry], today: NaiveDate) -> Vec<HistoryEvent> {
+    let confirmand = &details.confirmand;
+    let created_by = audit
+        .iter()
+        .find(|entry| entry.entity == "confirmand" && entry.action == AuditAction::Create)
+        .and_then(|entry| entry.username.clone());
+    let mut events = vec![event(confirmand.creation_date, HistoryEventKind::Registered, "Registered".to_string(), created_by)];
+
+    for entry in audit.iter().filter(|entry| entry.entity == "confirmand" && entry.action == AuditAction::Update) {
+        events.extend(field_events(entry));
+    }
+    group_events(&mut events, &details.group_history, audit, today);
+    for entry in audit.iter().filter(|entry| entry.entity == "enrollment" && entry.action == AuditAction::Delete) {
+        let group_id = entry.entity_id.map(|id| format!(" {}", id)).unwrap_or_default();
+        events.push(event(entry.changed_at, HistoryEventKind::LeftGroup, format!("Left group{}", group_id), entry.username.clone()));
+    }
+
+    for record in &details.sacraments {
+        let recorded = audit.iter().find(|entry| {
+            entry.entity == "sacrament_record"
+                && entry.action == AuditAction::Create
+                && entry.entity_id == Some(record.sacrament.id as i32)
+        });
+        let mut summary = format!("Received {}", record.sacrament.name);
+        if let Some(place) = record.details.church.as_deref().or(record.details.parish.as_deref()) {
+            summary.push_str(&format!(" at {}", place));
+        }
+        let username = recorded.and_then(|entry| entry.username.clone());
+        // Records without a date only show up if we know when they were entered.
+        match (record.details.received_date, recorded) {
+            (Some(date), _) => events.push(dated(date, HistoryEventKind::SacramentReceived, summary, username)),
+            (None, Some(entry)) => events.push(event(entry.changed_at, HistoryEventKind::SacramentReceived, summary, username)),
+            (None, None) => {}
+        }
+    }
+
+    events.sort_by_key(|event| (event.date, event.recorded_at));
+    events
+}
+
+// One audited update of the participant's own fields. Archiving and restoring get their own events.
+fn field_events(entry: &AuditEntry) -> Vec<HistoryEvent> {
+    let (Some(Value::Object(before)), Some(Value::Object(after))) = (&entry.before, &entry.after) else {
+        return Vec::new();
+    };
+    let mut events = Vec::new();
+    let mut changes = Vec::new();
+    for (field, old) in before {
+        let new = after.get(field).cloned().unwrap_or(Value::Null);
+        if field == "archived_at" {
+            let kind = if new.is_null() { HistoryEventKind::Restored } else { HistoryEventKind::Archived };
+            let summary = if new.is_null() { "Restored" } else { "Archived" };
+            events.push(event(entry.changed_at, kind, summary.to_string(), entry.username.clone()));
+        } else if !IGNORED_FIELDS.contains(&field.as_str()) {
+            changes.push(FieldChange { field: field.clone(), before: old.clone(), after: new });
+        }
+    }
+    if !changes.is_empty() {
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        let mut changed = event(entry.changed_at, HistoryEventKind::FieldsChanged, format!("Changed {}", fields.join(", ")), entry.username.clone());
+        changed.changes = changes;
+        events.push(changed);
+    }
+    events
+}
+
+// Joining each group, moving to another group of the same module, and completing a module. A group
+// is joined on the day the enrollment was recorded, or on its start date for older enrollments.
+fn group_events(events: &mut Vec<HistoryEvent>, group_history: &[GroupSummary], audit: &[AuditEntry], today: NaiveDate) {
+    let mut groups: Vec<&GroupSummary> = group_history.iter().collect();
+    groups.sort_by_key(|group| group.start_date);
+
+    for (index, group) in groups.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|i| groups[i]);
+        let next = groups.get(index + 1);
+        let enrollment = audit.iter().find(|entry| {
+            entry.entity == "enrollment" && entry.action == AuditAction::Create && entry.entity_id == Some(group.id)
+        });
+        let (kind, mut summary) = match previous {
+            Some(previous) if previous.module == group.module => (
+                HistoryEventKind::Transferred,
+                format!("Moved from group {} to group {} (module {})", previous.id, group.id, group.module),
+            ),
+            _ => (HistoryEventKind::Enrolled, format!("Joined group {} (module {})", group.id, group.module)),
+        };
+        if let Some(catechist) = &group.catechist_name {
+            summary.push_str(&format!(", led by {}", catechist));
+        }
+        let username = enrollment.and_then(|entry| entry.username.clone());
+        events.push(match enrollment {
+            Some(entry) => event(entry.changed_at, kind, summary, username),
+            None => dated(group.start_date, kind, summary, username),
+        });
+
+        // A group left for another one of the same module does not complete it.
+        let moved_on = next.is_some_and(|next| next.module == group.module);
+        if let Some(end_date) = group.end_date.filter(|end| *end < today && !moved_on) {
+            events.push(dated(end_date, HistoryEventKind::ModuleCompleted, format!("Completed module {}", group.module), None));
+        }
+    }
+}
+
+fn event(at: DateTime<Utc>, kind: HistoryEventKind, summary: String, username: Option<String>) -> HistoryEvent {
+    HistoryEvent { date: at.date_naive(), recorded_at: Some(at), kind, summary, changes: Vec::new(), username }
+}
+
+fn dated(date: NaiveDate, kind: HistoryEventKind, summary: String, username: Option<String>) -> HistoryEvent {
+    HistoryEvent { date, recorded_at: None, kind, summary, changes: Vec::new(), username }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+    use crate::models::{Confirmand, Sacrament, SacramentRecord, SacramentRecordDetails};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 10, 0, 0).unwrap()
+    }
+
+    fn details(group_history: Vec<GroupSummary>, sacraments: Vec<SacramentRecord>) -> ConfirmandDetails {
+        ConfirmandDetails {
+            confirmand: Confirmand { creation_date: at(2024, 9, 1), ..Confirmand::sample(1, "Ana Silva", date(2010, 3, 4)) },
+            sacraments,
+            group_history,
+            sponsor: None,
+            guardians: Vec::new(),
+            household: None,
+        }
+    }
+
+    fn group(id: i32, module: i16, start_date: NaiveDate, end_date: Option<NaiveDate>) -> GroupSummary {
+        GroupSummary { id, module, start_date, end_date, catechist_name: None }
+    }
+
+    fn audit(entity: &str, entity_id: i32, action: AuditAction, before: Value, after: Value, changed_at: DateTime<Utc>) -> AuditEntry {
+        AuditEntry {
+            id: 1,
+            user_id: Some(7),
+            username: Some("coordinator".to_string()),
+            entity: entity.to_string(),
+            entity_id: Some(entity_id),
+            confirmand_id: Some(1),
+            action,
+            before: Some(before),
+            after: Some(after),
+            changed_at,
+        }
+    }
+
+    fn kinds(events: &[HistoryEvent]) -> Vec<HistoryEventKind> {
+        events.iter().map(|event| event.kind.clone()).collect()
+    }
+
+    #[test]
+    fn orders_groups_transfers_and_completed_modules() {
+        let history = vec![
+            group(3, 2, date(2025, 10, 1), None),
+            group(2, 1, date(2025, 1, 10), Some(date(2025, 6, 30))),
+            group(1, 1, date(2024, 10, 1), Some(date(2025, 6, 30))),
+        ];
+        let events = build(&details(history, Vec::new()), &[], date(2026, 1, 1));
+        assert_eq!(
+            kinds(&events),
+            vec![
+                HistoryEventKind::Registered,
+                HistoryEventKind::Enrolled,
+                HistoryEventKind::Transferred,
+                HistoryEventKind::ModuleCompleted,
+                HistoryEventKind::Enrolled,
+            ]
+        );
+        assert_eq!(events[2].summary, "Moved from group 1 to group 2 (module 1)");
+        assert_eq!(events[3].date, date(2025, 6, 30));
+    }
+
+    #[test]
+    fn splits_field_changes_from_archiving() {
+        let update = audit(
+            "confirmand",
+            1,
+            AuditAction::Update,
+            json!({ "address": "Rua A", "archived_at": null }),
+            json!({ "address": "Rua B", "archived_at": "2025-02-01T10:00:00Z" }),
+            at(2025, 2, 1),
+        );
+        let events = build(&details(Vec::new(), Vec::new()), &[update], date(2026, 1, 1));
+        assert_eq!(kinds(&events), vec![HistoryEventKind::Registered, HistoryEventKind::Archived, HistoryEventKind::FieldsChanged]);
+        let changed = &events[2];
+        assert_eq!(changed.summary, "Changed address");
+        assert_eq!(changed.changes, vec![FieldChange { field: "address".to_string(), before: json!("Rua A"), after: json!("Rua B") }]);
+        assert_eq!(changed.username.as_deref(), Some("coordinator"));
+    }
+
+    #[test]
+    fn dates_sacraments_by_reception_or_by_when_they_were_recorded() {
+        let record = |id: i16, name: &str, received_date: Option<NaiveDate>| SacramentRecord {
+            sacrament: Sacrament { id, name: name.to_string(), code: None },
+            details: SacramentRecordDetails {
+                received_date,
+                church: Some("Sé".to_string()),
+                parish: None,
+                officiant: None,
+                register_book: None,
+                register_page: None,
+                register_entry: None,
+            },
+        };
+        let sacraments = vec![record(1, "Baptism", Some(date(2010, 5, 2))), record(2, "First Communion", None), record(3, "Penance", None)];
+        let recorded = audit("sacrament_record", 2, AuditAction::Create, Value::Null, json!({}), at(2024, 9, 2));
+        let events = build(&details(Vec::new(), sacraments), &[recorded], date(2026, 1, 1));
+        let summaries: Vec<&str> = events.iter().map(|event| event.summary.as_str()).collect();
+        assert_eq!(summaries, vec!["Received Baptism at Sé", "Registered", "Received First Communion at Sé"]);
+    }
+}
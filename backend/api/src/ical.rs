@@ -0,0 +1,234 @@
+use chrono::{NaiveDate, NaiveTime, Utc, Weekday};
+use crate::schedule::first_weekday_on_or_after;
+
+// The PRODID we advertise in every generated calendar (RFC 5545, section 3.7.3).
+const PRODID: &str = "-//NSP Crisma//Confirmation Groups//EN";
+
+// A weekly event built from a group's schedule. Without a start and end time it is an all-day event.
+pub struct WeeklyEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub weekday: Weekday,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    // Dates on which the group does not meet (parish holidays), emitted as EXDATE.
+    pub excluded_dates: Vec<NaiveDate>,
+}
+
+// Renders a full VCALENDAR document. Lines are CRLF-terminated and folded at 75 octets.
+pub fn render_calendar(name: &str, events: &[WeeklyEvent]) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(name)));
+
+    let dtstamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    for event in events {
+        render_event(&mut out, event, &dtstamp);
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+fn render_event(out: &mut String, event: &WeeklyEvent, dtstamp: &str) {
+    // The first occurrence must fall on the group's weekday, otherwise calendar clients
+    // show an extra meeting on the start date itself.
+    let first = first_weekday_on_or_after(event.start_date, event.weekday);
+    if event.end_date.is_some_and(|end| first > end) {
+        return;
+    }
+
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", event.uid));
+    push_line(out, &format!("DTSTAMP:{}", dtstamp));
+    // Timed events use floating local times, which calendar apps show in the parish's own time zone.
+    let times = event.start_time.zip(event.end_time);
+    let format_occurrence = |date: NaiveDate| match times {
+        Some((start, _)) => format_date_time(date, start),
+        None => format_date(date),
+    };
+    let value_type = if times.is_some() { "" } else { ";VALUE=DATE" };
+
+    match times {
+        Some((start, end)) => {
+            push_line(out, &format!("DTSTART:{}", format_date_time(first, start)));
+            push_line(out, &format!("DTEND:{}", format_date_time(first, end)));
+        }
+        None => {
+            push_line(out, &format!("DTSTART;VALUE=DATE:{}", format_date(first)));
+            push_line(out, &format!("DTEND;VALUE=DATE:{}", format_date(first.succ_opt().unwrap_or(first))));
+        }
+    }
+
+    let mut rrule = format!("RRULE:FREQ=WEEKLY;BYDAY={}", byday(event.weekday));
+    if let Some(end) = event.end_date {
+        // UNTIL must have the same value type as DTSTART, so timed events end at 23:59:59.
+        let until = match times {
+            Some(_) => format_date_time(end, NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+            None => format_date(end),
+        };
+        rrule.push_str(&format!(";UNTIL={}", until));
+    }
+    push_line(out, &rrule);
+
+    if !event.excluded_dates.is_empty() {
+        let dates: Vec<String> = event.excluded_dates.iter().map(|d| format_occurrence(*d)).collect();
+        push_line(out, &format!("EXDATE{}:{}", value_type, dates.join(",")));
+    }
+
+    push_line(out, &format!("SUMMARY:{}", escape_text(&event.summary)));
+    if let Some(description) = &event.description {
+        push_line(out, &format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    if let Some(location) = &event.location {
+        push_line(out, &format!("LOCATION:{}", escape_text(location)));
+    }
+    if let Some(url) = &event.url {
+        push_line(out, &format!("URL:{}", url));
+    }
+    push_line(out, "END:VEVENT");
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn format_date_time(date: NaiveDate, time: NaiveTime) -> String {
+    format!("{}T{}", format_date(date), time.format("%H%M%S"))
+}
+
+fn byday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+// Escapes a TEXT value as described in RFC 5545, section 3.3.11.
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Appends a content line, folding it so that no physical line exceeds 75 octets.
+// We never split inside a multi-byte UTF-8 character.
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    // A Saturday group starting on Wednesday 2025-10-01.
+    fn event(times: Option<(NaiveTime, NaiveTime)>, end_date: Option<NaiveDate>) -> WeeklyEvent {
+        WeeklyEvent {
+            uid: "group-1@crisma".to_string(),
+            summary: "Module 1".to_string(),
+            description: None,
+            location: None,
+            url: None,
+            weekday: Weekday::Sat,
+            start_date: date(2025, 10, 1),
+            end_date,
+            start_time: times.map(|(start, _)| start),
+            end_time: times.map(|(_, end)| end),
+            excluded_dates: Vec::new(),
+        }
+    }
+
+    fn lines(calendar: &str) -> Vec<&str> {
+        calendar.split("\r\n").collect()
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(escape_text("a\\b;c,d\r\ne\nf"), "a\\\\b\\;c\\,d\\ne\\nf");
+    }
+
+    #[test]
+    fn folds_long_lines_without_splitting_characters() {
+        let mut out = String::new();
+        push_line(&mut out, &"a".repeat(80));
+        assert_eq!(out, format!("{}\r\n {}\r\n", "a".repeat(75), "a".repeat(5)));
+
+        let mut out = String::new();
+        push_line(&mut out, &format!("{}é", "a".repeat(74)));
+        assert_eq!(out, format!("{}\r\n é\r\n", "a".repeat(74)));
+        assert!(out.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn timed_events_start_on_the_weekday_and_repeat_until_the_end_of_the_last_day() {
+        let calendar = render_calendar("Groups", &[event(Some((time(15, 0), time(16, 30))), Some(date(2026, 6, 20)))]);
+        let lines = lines(&calendar);
+        assert!(lines.contains(&"DTSTART:20251004T150000"));
+        assert!(lines.contains(&"DTEND:20251004T163000"));
+        assert!(lines.contains(&"RRULE:FREQ=WEEKLY;BYDAY=SA;UNTIL=20260620T235959"));
+    }
+
+    #[test]
+    fn events_without_times_are_all_day() {
+        let calendar = render_calendar("Groups", &[event(None, Some(date(2026, 6, 20)))]);
+        let lines = lines(&calendar);
+        assert!(lines.contains(&"DTSTART;VALUE=DATE:20251004"));
+        assert!(lines.contains(&"DTEND;VALUE=DATE:20251005"));
+        assert!(lines.contains(&"RRULE:FREQ=WEEKLY;BYDAY=SA;UNTIL=20260620"));
+    }
+
+    #[test]
+    fn events_ending_before_their_first_meeting_are_left_out() {
+        let calendar = render_calendar("Groups", &[event(None, Some(date(2025, 10, 3)))]);
+        assert!(!calendar.contains("BEGIN:VEVENT"));
+        let open_ended = render_calendar("Groups", &[event(None, None)]);
+        assert!(lines(&open_ended).contains(&"RRULE:FREQ=WEEKLY;BYDAY=SA"));
+    }
+
+    #[test]
+    fn holidays_are_excluded_with_the_event_value_type() {
+        let mut timed = event(Some((time(15, 0), time(16, 30))), None);
+        timed.excluded_dates = vec![date(2025, 12, 27), date(2026, 1, 3)];
+        let mut all_day = event(None, None);
+        all_day.excluded_dates = vec![date(2025, 12, 27)];
+        let calendar = render_calendar("Groups", &[timed, all_day]);
+        let lines = lines(&calendar);
+        assert!(lines.contains(&"EXDATE:20251227T150000,20260103T150000"));
+        assert!(lines.contains(&"EXDATE;VALUE=DATE:20251227"));
+    }
+}
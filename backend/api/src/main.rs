@@ -5,10 +5,20 @@ use axum::{
 use std::sync::Arc;
 use tokio::net::TcpListener;
 
+mod assignment;
+mod certificates;
 mod db;
+mod duplicates;
+mod eligibility;
+mod etag;
 mod handlers;
+mod history;
+mod ical;
 mod auth;
 mod models;
+mod roster;
+mod schedule;
+mod validation;
 
 pub type AppState = Arc<db::DBPool>;
 
@@ -30,38 +40,162 @@ async fn main() {
     let confirmands_routes = Router::new()
         .route("/", get(handlers::list_confirmands).post(handlers::create_confirmand))
         .route("/import", post(handlers::import_confirmands_from_csv))
-        .route("/:id", put(handlers::update_confirmand).delete(handlers::delete_confirmand))
+        .route("/archived", get(handlers::list_archived_confirmands))
+        .route("/duplicates", get(handlers::find_duplicate_confirmands))
+        .route(
+            "/:id",
+            put(handlers::update_confirmand)
+                .patch(handlers::patch_confirmand)
+                .delete(handlers::delete_confirmand),
+        )
+        .route("/:id/restore", post(handlers::restore_confirmand))
+        .route("/:id/merge", post(handlers::merge_confirmands))
+        .route("/:id/purge", delete(handlers::purge_confirmand))
         .route("/:id/details", get(handlers::get_participant_details))
+        .route("/:id/eligibility", get(handlers::get_confirmand_eligibility))
+        .route("/:id/history", get(handlers::get_confirmand_history))
+        .route(
+            "/:id/emergency-info",
+            get(handlers::get_emergency_info).put(handlers::update_emergency_info),
+        )
+        .route("/:id/certificates/:file", get(handlers::get_confirmand_certificate))
+        .route(
+            "/:id/sponsor",
+            get(handlers::get_sponsor).put(handlers::upsert_sponsor).delete(handlers::delete_sponsor),
+        )
+        .route("/:id/guardians", get(handlers::list_guardians).post(handlers::link_guardian))
+        .route("/:id/consents", get(handlers::list_consents).post(handlers::create_consent))
+        .route("/:id/consents/:consentId", delete(handlers::delete_consent))
+        .route(
+            "/:id/guardians/:guardianId",
+            put(handlers::update_guardian).delete(handlers::unlink_guardian_from_participant),
+        )
         .route("/:id/sacraments", post(handlers::add_sacrament_to_participant))
+        .route(
+            "/:id/preferred-days",
+            get(handlers::get_preferred_days).put(handlers::update_preferred_days),
+        )
         .route(
             "/:confirmandId/sacraments/:sacramentId",
-            delete(handlers::remove_sacrament_from_participant),
+            put(handlers::update_participant_sacrament).delete(handlers::remove_sacrament_from_participant),
         );
 
     // Define routes for Catechists
     let catechists_routes = Router::new()
         .route("/", get(handlers::list_catechists).post(handlers::create_catechist))
-        .route("/:id/details", get(handlers::get_catechist_details));
+        .route("/availability", get(handlers::find_available_catechists))
+        .route("/:id/details", get(handlers::get_catechist_details))
+        .route(
+            "/:id/availability",
+            get(handlers::get_catechist_availability).put(handlers::update_catechist_availability),
+        )
+        .route(
+            "/:id/calendar-feeds",
+            get(handlers::list_catechist_calendar_feeds).post(handlers::create_catechist_calendar_feed),
+        );
 
     // Define routes for Groups
     let groups_routes = Router::new()
         .route("/", get(handlers::list_groups).post(handlers::create_group))
+        .route("/assignments/propose", post(handlers::propose_group_assignments))
+        .route("/assignments/apply", post(handlers::apply_group_assignments))
         .route("/:id", get(handlers::get_group_details))
         .route("/:id/participants", post(handlers::add_participant_to_group))
+        .route("/:id/sessions", get(handlers::get_group_sessions))
+        .route("/:id/contacts", get(handlers::get_group_family_contacts))
+        .route("/:id/eligibility", get(handlers::get_group_eligibility))
+        .route("/:id/certificates/:file", get(handlers::get_group_certificates))
+        // `roster.pdf` or `roster.csv`
+        .route("/:id/:file", get(handlers::get_group_roster))
+        .route("/:id/room", put(handlers::assign_group_room))
+        .route("/:id/sessions/:date/room", put(handlers::assign_session_room))
+        .route(
+            "/:id/calendar-feeds",
+            get(handlers::list_group_calendar_feeds).post(handlers::create_group_calendar_feed),
+        )
         .route(
             "/:groupId/participants/:participantId",
             delete(handlers::remove_participant_from_group),
         );
 
+    // Define routes for the parish holiday calendar
+    let holidays_routes = Router::new()
+        .route("/", get(handlers::list_parish_holidays).post(handlers::create_parish_holiday))
+        .route("/preview", post(handlers::preview_parish_holiday))
+        .route("/:id", delete(handlers::delete_parish_holiday));
+
+    // Define routes for locations and rooms
+    let locations_routes = Router::new()
+        .route("/", get(handlers::list_locations).post(handlers::create_location))
+        .route("/:id/rooms", post(handlers::create_room));
+
+    let rooms_routes = Router::new()
+        .route("/", get(handlers::list_rooms))
+        .route("/check", post(handlers::check_room_booking));
+
+    // Define routes for catechetical years
+    let years_routes = Router::new()
+        .route("/", get(handlers::list_catechetical_years).post(handlers::create_catechetical_year))
+        .route("/rollover", post(handlers::rollover_catechetical_year))
+        .route("/:id/activate", post(handlers::activate_catechetical_year));
+
+    // Define routes for Households (families)
+    let households_routes = Router::new()
+        .route("/", get(handlers::list_households).post(handlers::create_household))
+        .route(
+            "/:id",
+            get(handlers::get_household_details).put(handlers::update_household).delete(handlers::delete_household),
+        )
+        .route("/:id/members", post(handlers::add_household_members))
+        .route("/:id/confirmands/:confirmandId", delete(handlers::remove_household_confirmand))
+        .route("/:id/guardians/:guardianId", delete(handlers::remove_household_guardian));
+
+    // Define routes for Confirmation Ceremonies
+    let ceremonies_routes = Router::new()
+        .route("/", get(handlers::list_ceremonies).post(handlers::create_ceremony))
+        .route("/eligible-candidates", get(handlers::list_eligible_ceremony_candidates))
+        .route("/:id", get(handlers::get_ceremony_details).delete(handlers::delete_ceremony))
+        .route("/:id/candidates", post(handlers::add_ceremony_candidates))
+        .route("/:id/candidates/:confirmandId", delete(handlers::remove_ceremony_candidate))
+        .route("/:id/finalize", post(handlers::finalize_ceremony));
+
     // Combine all the routers into the main app router using `nest`
     let app = Router::new()
         .route("/api/dashboard/stats", get(handlers::get_dashboard_stats))
+        .route("/api/reports/overview", get(handlers::get_reports_overview))
         .route("/api/sacraments", get(handlers::list_all_sacraments))
+        .route(
+            "/api/certificates/settings",
+            get(handlers::get_certificate_settings).put(handlers::update_certificate_settings),
+        )
+        .route(
+            "/api/sensitive-data/grants",
+            get(handlers::get_sensitive_data_grants).put(handlers::update_sensitive_data_grants),
+        )
+        .route("/api/audit", get(handlers::list_audit_entries))
+        .route("/api/users", get(handlers::list_users))
+        .route("/api/users/:id/access", put(handlers::update_user_access))
+        .route("/api/certificates/templates", get(handlers::list_certificate_templates))
+        .route("/api/certificates/templates/:kind", put(handlers::update_certificate_template))
+        .route(
+            "/api/eligibility/requirements",
+            get(handlers::list_eligibility_requirements).put(handlers::update_eligibility_requirements),
+        )
+        // Public, token-protected iCalendar feeds (e.g. `/api/calendar/<token>.ics`)
+        .route("/api/calendar/:file", get(handlers::get_calendar_feed))
+        .route("/api/calendar-feeds/:id", delete(handlers::revoke_calendar_feed))
         .nest("/api/confirmands", confirmands_routes)
         .nest("/api/catechists", catechists_routes)
         .nest("/api/groups", groups_routes)
+        .nest("/api/holidays", holidays_routes)
+        .nest("/api/locations", locations_routes)
+        .nest("/api/rooms", rooms_routes)
+        .nest("/api/years", years_routes)
+        .nest("/api/households", households_routes)
+        .nest("/api/ceremonies", ceremonies_routes)
         .nest("/api/auth", auth_routes)
         //.layer(middleware::from_fn(auth::auth_middleware))
+        .layer(middleware::map_response(validation::json_field_errors))
         .with_state(app_state);
 
     // Bind to the port provided by the platform via the `PORT` env var
@@ -1,7 +1,9 @@
-use chrono::{DateTime, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Deserializer, Serialize};
 use tokio_postgres::Row;
 use strum::{Display, EnumString};
+use uuid::Uuid;
+use crate::assignment::age_on;
 
 #[derive(Serialize, Clone, Debug)]
 pub struct User {
@@ -9,6 +11,36 @@ pub struct User {
     pub username: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum UserRole {
+    Admin,
+    Coordinator,
+    Secretary,
+    Catechist,
+}
+
+// An account as administrators see it, with the role and catechist that decide what it may do.
+#[derive(Serialize)]
+pub struct UserAccount {
+    pub id: i32,
+    pub username: String,
+    pub role: UserRole,
+    pub catechist_id: Option<i32>,
+}
+
+// Body of `PUT /api/users/:id/access`.
+#[derive(Deserialize)]
+pub struct UpdateUserAccess {
+    pub role: UserRole,
+    pub catechist_id: Option<i32>,
+}
+
+// The roles allowed to read emergency contacts and medical notes.
+#[derive(Serialize, Deserialize)]
+pub struct SensitiveDataGrants {
+    pub roles: Vec<UserRole>,
+}
+
 // ===================================================================
 // --- NEW --- Dashboard Models --- NEW ---
 // ===================================================================
@@ -20,6 +52,59 @@ pub struct DashboardStats {
     pub active_group_count: i64,
 }
 
+// ===================================================================
+// Reports Models
+// ===================================================================
+
+#[derive(Serialize, Clone)]
+pub struct ModuleEnrollment {
+    pub catechetical_year_id: Option<i32>,
+    pub year_name: Option<String>,
+    pub module: i16,
+    pub participant_count: i64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct MonthlyCount {
+    pub month: NaiveDate, // First day of the month
+    pub count: i64,
+}
+
+// Outcome of everyone whose group for a module has ended: they either continued
+// (a later module or Confirmation) or dropped out.
+#[derive(Serialize, Clone)]
+pub struct ModuleOutcome {
+    pub module: i16,
+    pub finished_count: i64,
+    pub completed_count: i64,
+    pub dropped_out_count: i64,
+    pub completion_rate: f64,
+    pub dropout_rate: f64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct YearlyCount {
+    pub catechetical_year_id: Option<i32>,
+    pub year_name: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct AgeBucket {
+    pub label: String,
+    pub count: i64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct ReportsOverview {
+    pub generated_at: DateTime<Utc>,
+    pub enrollment_by_module: Vec<ModuleEnrollment>,
+    pub registrations_by_month: Vec<MonthlyCount>,
+    pub module_outcomes: Vec<ModuleOutcome>,
+    pub confirmations_by_year: Vec<YearlyCount>,
+    pub age_distribution: Vec<AgeBucket>,
+}
+
 // ===================================================================
 // Custom ENUM Types
 // ===================================================================
@@ -53,6 +138,20 @@ pub enum DayOfTheWeek {
     Saturday,
 }
 
+impl DayOfTheWeek {
+    pub fn to_weekday(&self) -> Weekday {
+        match self {
+            DayOfTheWeek::Sunday => Weekday::Sun,
+            DayOfTheWeek::Monday => Weekday::Mon,
+            DayOfTheWeek::Tuesday => Weekday::Tue,
+            DayOfTheWeek::Wednesday => Weekday::Wed,
+            DayOfTheWeek::Thursday => Weekday::Thu,
+            DayOfTheWeek::Friday => Weekday::Fri,
+            DayOfTheWeek::Saturday => Weekday::Sat,
+        }
+    }
+}
+
 // ===================================================================
 // --- NEW --- Group Summary Model --- NEW ---
 // ===================================================================
@@ -63,6 +162,7 @@ pub struct GroupSummary {
     pub id: i32,
     pub module: i16,
     pub start_date: NaiveDate, // --- NEW ---
+    pub end_date: Option<NaiveDate>,
     pub catechist_name: Option<String>,
 }
 // ===================================================================
@@ -78,12 +178,80 @@ pub struct CreateConfirmand {
     pub phone_number: String,
     pub email: String,
     pub marital_status: MaritalStatus,
+    // Stored as the linked guardians with the Father / Mother relationship.
     pub father_name: Option<String>,
     pub mother_name: Option<String>,
+    // Stored on the Baptism / First Communion sacrament records, not on the confirmand.
     pub baptism_church: Option<String>,
     pub communion_church: Option<String>,
+    // Required for minors who have no participation consent on record yet.
+    #[serde(default)]
+    pub guardian_consent: Option<GuardianConsent>,
+}
+
+// Body of `PATCH /api/confirmands/:id`. Fields left out are kept; optional fields can be cleared
+// with an explicit `null`. Required fields sent as `null` are refused by `Validate`.
+#[derive(Deserialize)]
+pub struct PatchConfirmand {
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub full_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub birth_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub address: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub phone_number: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub email: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub marital_status: Option<Option<MaritalStatus>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub father_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub mother_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub baptism_church: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub communion_church: Option<Option<String>>,
+    #[serde(default)]
+    pub guardian_consent: Option<GuardianConsent>,
+}
+
+impl PatchConfirmand {
+    // The full update this patch amounts to, given the confirmand as currently stored. A `null`
+    // required field keeps the stored value; validate the patch first to refuse it instead.
+    pub fn apply_to(self, current: Confirmand) -> Result<CreateConfirmand, strum::ParseError> {
+        Ok(CreateConfirmand {
+            full_name: self.full_name.flatten().unwrap_or(current.full_name),
+            birth_date: self.birth_date.flatten().unwrap_or(current.birth_date),
+            address: self.address.flatten().unwrap_or(current.address),
+            phone_number: self.phone_number.flatten().unwrap_or(current.phone_number),
+            email: self.email.flatten().unwrap_or(current.email),
+            marital_status: match self.marital_status.flatten() {
+                Some(status) => status,
+                None => current.marital_status.parse()?,
+            },
+            father_name: self.father_name.unwrap_or(current.father_name),
+            mother_name: self.mother_name.unwrap_or(current.mother_name),
+            baptism_church: self.baptism_church.unwrap_or(current.baptism_church),
+            communion_church: self.communion_church.unwrap_or(current.communion_church),
+            guardian_consent: self.guardian_consent,
+        })
+    }
+}
+
+// Tells a field sent as `null` (`Some(None)`) apart from one left out (`None`, via `#[serde(default)]`).
+fn explicit_null<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
+// Age of majority in Portugal; younger confirmands need a guardian's consent.
+pub const ADULT_AGE: i32 = 18;
+
 // This struct now represents a full confirmand record, including all optional fields.
 #[derive(Serialize, Clone)]
 pub struct Confirmand {
@@ -94,18 +262,29 @@ pub struct Confirmand {
     pub phone_number: String,
     pub email: String,
     pub marital_status: String,
+    // Read-only, derived from the guardians linked as Father / Mother.
     pub father_name: Option<String>,
     pub mother_name: Option<String>,
+    // Read-only, derived from the Baptism / First Communion sacrament records.
     pub baptism_church: Option<String>,
     pub communion_church: Option<String>,
     pub creation_date: DateTime<Utc>,
     pub current_group_id: Option<i32>,
     pub current_group_module: Option<i16>,
-    pub current_group_start_date: Option<NaiveDate>
+    pub current_group_start_date: Option<NaiveDate>,
+    // Computed from `birth_date` on every read.
+    pub age: i32,
+    pub is_minor: bool,
+    // Bumped on every change to the row; sent back as the `ETag`.
+    pub version: i32,
+    // Set when the participant was archived (soft-deleted).
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl From<Row> for Confirmand {
     fn from(row: Row) -> Self {
+        let birth_date: NaiveDate = row.get("birth_date");
+        let age = age_on(birth_date, Utc::now().date_naive());
         Self {
             id: row.get("id"),
             full_name: row.get("full_name"),
@@ -121,7 +300,40 @@ impl From<Row> for Confirmand {
             creation_date: row.get("creation_date"),
             current_group_id: row.get("current_group_id"),
             current_group_module: row.get("current_group_module"),
-            current_group_start_date: row.get("current_group_start_date")
+            current_group_start_date: row.get("current_group_start_date"),
+            age,
+            is_minor: age < ADULT_AGE,
+            version: row.get("version"),
+            archived_at: row.get("archived_at"),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Confirmand {
+    // A participant with just a name and birth date, for tests; set anything else with `..`.
+    pub fn sample(id: i32, full_name: &str, birth_date: NaiveDate) -> Self {
+        let age = age_on(birth_date, Utc::now().date_naive());
+        Self {
+            id,
+            full_name: full_name.to_string(),
+            birth_date,
+            address: String::new(),
+            phone_number: String::new(),
+            email: String::new(),
+            marital_status: "Single".to_string(),
+            father_name: None,
+            mother_name: None,
+            baptism_church: None,
+            communion_church: None,
+            creation_date: Utc::now(),
+            current_group_id: None,
+            current_group_module: None,
+            current_group_start_date: None,
+            age,
+            is_minor: age < ADULT_AGE,
+            version: 1,
+            archived_at: None,
         }
     }
 }
@@ -134,6 +346,7 @@ impl From<Row> for Confirmand {
 pub struct CreateCatechist {
     pub full_name: String,
     pub currently_active: bool,
+    pub max_concurrent_groups: Option<i16>,
 }
 
 #[derive(Serialize, Clone, Debug)] // --- THIS IS THE KEY FIX ---
@@ -144,6 +357,11 @@ pub struct Catechist {
     pub latest_group_id: Option<i32>,
     pub latest_group_module: Option<i16>,
     pub latest_group_start_date: Option<NaiveDate>,
+    // Workload: groups that have not ended yet and the members enrolled in them
+    pub max_concurrent_groups: Option<i16>,
+    pub active_group_count: i64,
+    pub total_member_count: i64,
+    pub version: i32,
 }
 
 impl From<Row> for Catechist {
@@ -156,10 +374,47 @@ impl From<Row> for Catechist {
             latest_group_id: row.get("latest_group_id"),
             latest_group_module: row.get("latest_group_module"),
             latest_group_start_date: row.get("latest_group_start_date"),
+            max_concurrent_groups: row.get("max_concurrent_groups"),
+            active_group_count: row.get("active_group_count"),
+            total_member_count: row.get("total_member_count"),
+            version: row.get("version"),
+        }
+    }
+}
+
+// A weekly time slot in which a catechist is available to lead a group.
+#[derive(Serialize, Deserialize)]
+pub struct AvailabilitySlot {
+    pub day_of_the_week: DayOfTheWeek,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+impl From<Row> for AvailabilitySlot {
+    fn from(row: Row) -> Self {
+        let day: String = row.get("day_of_the_week");
+        Self {
+            // The column is a Postgres enum, so it always holds one of our variants.
+            day_of_the_week: day.parse().expect("unknown day_of_week_enum value"),
+            start_time: row.get("start_time"),
+            end_time: row.get("end_time"),
         }
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CatechistAvailability {
+    pub max_concurrent_groups: Option<i16>,
+    pub slots: Vec<AvailabilitySlot>,
+}
+
+#[derive(Deserialize)]
+pub struct AvailabilityQuery {
+    pub day: DayOfTheWeek,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+}
+
 #[derive(Serialize)]
 pub struct CatechistDetails {
     #[serde(flatten)]
@@ -179,6 +434,11 @@ pub struct CreateConfirmationGroup {
     pub group_link: Option<String>,
     pub start_date: NaiveDate,
     pub end_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub room_id: Option<i32>,
+    // When omitted, the group is attached to the year its start_date falls in.
+    pub catechetical_year_id: Option<i32>,
 }
 
 #[derive(Serialize)]
@@ -191,6 +451,12 @@ pub struct ConfirmationGroup {
     pub group_link: Option<String>,
     pub start_date: NaiveDate,
     pub end_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub room_id: Option<i32>,
+    pub room_name: Option<String>,
+    pub catechetical_year_id: Option<i32>,
+    pub version: i32,
 }
 
 impl From<Row> for ConfirmationGroup {
@@ -204,6 +470,12 @@ impl From<Row> for ConfirmationGroup {
             group_link: row.get("group_link"),
             start_date: row.get("start_date"),
             end_date: row.get("end_date"),
+            start_time: row.get("start_time"),
+            end_time: row.get("end_time"),
+            room_id: row.get("room_id"),
+            room_name: row.get("room_name"),
+            catechetical_year_id: row.get("catechetical_year_id"),
+            version: row.get("version"),
         }
     }
 }
@@ -220,6 +492,12 @@ pub struct ConfirmationGroupDetails {
     pub catechist_name: Option<String>,
     pub day_of_the_week: String,
     pub start_date: NaiveDate,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub room_id: Option<i32>,
+    pub room_name: Option<String>,
+    pub version: i32,
+    pub session_rooms: Vec<SessionRoom>,
     pub members: Vec<Confirmand>,
 }
 
@@ -231,6 +509,7 @@ pub struct ConfirmationGroupDetails {
 pub struct Sacrament {
     pub id: i16,
     pub name: String,
+    pub code: Option<String>, // e.g. "baptism", "confirmation"
 }
 
 impl From<Row> for Sacrament {
@@ -238,19 +517,974 @@ impl From<Row> for Sacrament {
         Self {
             id: row.get("id"),
             name: row.get("name"),
+            code: row.get("code"),
         }
     }
 }
 
+// Where, when and by whom a sacrament was received, as written in the parish register.
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct SacramentRecordDetails {
+    pub received_date: Option<NaiveDate>,
+    pub church: Option<String>,
+    pub parish: Option<String>,
+    pub officiant: Option<String>,
+    pub register_book: Option<String>,
+    pub register_page: Option<String>,
+    pub register_entry: Option<String>,
+}
+
+impl From<&Row> for SacramentRecordDetails {
+    fn from(row: &Row) -> Self {
+        Self {
+            received_date: row.get("received_date"),
+            church: row.get("church"),
+            parish: row.get("parish"),
+            officiant: row.get("officiant"),
+            register_book: row.get("register_book"),
+            register_page: row.get("register_page"),
+            register_entry: row.get("register_entry"),
+        }
+    }
+}
+
+// Body for `POST /api/confirmands/:id/sacraments`. Only `sacrament_id` is required.
 #[derive(Deserialize)]
 pub struct UpdateParticipantSacrament {
     pub sacrament_id: i16,
+    #[serde(flatten)]
+    pub details: SacramentRecordDetails,
+}
+
+// A sacrament received by a participant, serialized as the sacrament's fields plus the register details.
+#[derive(Serialize, Clone, Debug)]
+pub struct SacramentRecord {
+    #[serde(flatten)]
+    pub sacrament: Sacrament,
+    #[serde(flatten)]
+    pub details: SacramentRecordDetails,
+}
+
+impl From<Row> for SacramentRecord {
+    fn from(row: Row) -> Self {
+        let details = SacramentRecordDetails::from(&row);
+        Self {
+            sacrament: Sacrament::from(row),
+            details,
+        }
+    }
 }
 
 #[derive(Serialize)]
 pub struct ConfirmandDetails {
     #[serde(flatten)]
     pub confirmand: Confirmand,
-    pub sacraments: Vec<Sacrament>,
+    pub sacraments: Vec<SacramentRecord>,
     pub group_history: Vec<GroupSummary>,
-}
\ No newline at end of file
+    pub sponsor: Option<Sponsor>,
+    pub guardians: Vec<Guardian>,
+    pub household: Option<Household>,
+}
+
+// ===================================================================
+// Calendar Feed Models
+// ===================================================================
+
+// A revocable, tokenized iCalendar feed for either a group or a catechist.
+#[derive(Serialize)]
+pub struct CalendarFeed {
+    pub id: i32,
+    pub token: Uuid,
+    pub confirmation_group_id: Option<i32>,
+    pub catechist_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<Row> for CalendarFeed {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            token: row.get("token"),
+            confirmation_group_id: row.get("confirmation_group_id"),
+            catechist_id: row.get("catechist_id"),
+            created_at: row.get("created_at"),
+            revoked_at: row.get("revoked_at"),
+        }
+    }
+}
+
+// ===================================================================
+// Parish Holiday & Session Schedule Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum HolidayKind {
+    Range,
+    Annual,
+    EasterRelative,
+}
+
+#[derive(Deserialize)]
+pub struct CreateParishHoliday {
+    pub name: String,
+    pub kind: HolidayKind,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub easter_offset_start: Option<i32>,
+    pub easter_offset_end: Option<i32>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ParishHoliday {
+    pub id: i32,
+    pub name: String,
+    pub kind: HolidayKind,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub easter_offset_start: Option<i32>,
+    pub easter_offset_end: Option<i32>,
+}
+
+impl From<Row> for ParishHoliday {
+    fn from(row: Row) -> Self {
+        let kind: String = row.get("kind");
+        Self {
+            id: row.get("id"),
+            name: row.get("name"),
+            // The column is a Postgres enum, so it always holds one of our variants.
+            kind: kind.parse().expect("unknown holiday_kind_enum value"),
+            start_date: row.get("start_date"),
+            end_date: row.get("end_date"),
+            easter_offset_start: row.get("easter_offset_start"),
+            easter_offset_end: row.get("easter_offset_end"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SkippedSession {
+    pub date: NaiveDate,
+    pub holiday_id: i32,
+    pub holiday_name: String,
+}
+
+#[derive(Serialize)]
+pub struct GroupSchedule {
+    pub group_id: i32,
+    pub sessions: Vec<NaiveDate>,
+    pub skipped: Vec<SkippedSession>,
+}
+
+// A currently scheduled session that a new holiday would cancel.
+#[derive(Serialize)]
+pub struct AffectedSession {
+    pub group_id: i32,
+    pub module: i16,
+    pub catechist_name: Option<String>,
+    pub date: NaiveDate,
+}
+
+// ===================================================================
+// Location & Room Models
+// ===================================================================
+
+#[derive(Deserialize)]
+pub struct CreateLocation {
+    pub name: String,
+    pub address: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Location {
+    pub id: i32,
+    pub name: String,
+    pub address: Option<String>,
+    pub rooms: Vec<Room>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateRoom {
+    pub name: String,
+    pub capacity: Option<i32>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct Room {
+    pub id: i32,
+    pub location_id: i32,
+    pub location_name: String,
+    pub name: String,
+    pub capacity: Option<i32>,
+}
+
+impl From<Row> for Room {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            location_id: row.get("location_id"),
+            location_name: row.get("location_name"),
+            name: row.get("name"),
+            capacity: row.get("capacity"),
+        }
+    }
+}
+
+// Body for `PUT /api/groups/:id/room` and `PUT /api/groups/:id/sessions/:date/room`.
+#[derive(Deserialize)]
+pub struct AssignRoom {
+    pub room_id: Option<i32>,
+}
+
+// A single session of a group that was moved to a different room.
+#[derive(Serialize)]
+pub struct SessionRoom {
+    pub session_date: NaiveDate,
+    pub room_id: i32,
+    pub room_name: String,
+}
+
+// A proposed booking to check against the existing use of a room.
+// Set `start_date` and `end_date` to the same day to check a single session.
+#[derive(Deserialize)]
+pub struct RoomBookingCheck {
+    pub room_id: i32,
+    pub day_of_the_week: DayOfTheWeek,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub exclude_group_id: Option<i32>,
+}
+
+// An existing booking that overlaps a proposed one. `session_date` is set when
+// the clash comes from a single moved session rather than the group's weekly slot.
+#[derive(Serialize)]
+pub struct RoomConflict {
+    pub group_id: i32,
+    pub module: i16,
+    pub day_of_the_week: String,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub session_date: Option<NaiveDate>,
+}
+
+// ===================================================================
+// Group Assignment Models
+// ===================================================================
+
+// Body for `POST /api/groups/assignments/propose`. Leave `module` empty to cover all modules.
+#[derive(Deserialize)]
+pub struct ProposeAssignments {
+    pub module: Option<i16>,
+    pub target_group_size: i64,
+}
+
+#[derive(Serialize)]
+pub struct ProposedAssignment {
+    pub confirmand_id: i32,
+    pub full_name: String,
+    pub age: i32,
+    pub group_id: i32,
+    pub module: i16,
+    pub day_of_the_week: String,
+    pub preference_met: bool,
+}
+
+#[derive(Serialize)]
+pub struct UnplacedConfirmand {
+    pub confirmand_id: i32,
+    pub full_name: String,
+    pub reason: String,
+}
+
+#[derive(Serialize)]
+pub struct AssignmentProposal {
+    pub assignments: Vec<ProposedAssignment>,
+    pub unplaced: Vec<UnplacedConfirmand>,
+}
+
+#[derive(Deserialize)]
+pub struct GroupAssignment {
+    pub confirmand_id: i32,
+    pub group_id: i32,
+}
+
+// Body for `POST /api/groups/assignments/apply`, usually the reviewed output of `propose`.
+#[derive(Deserialize)]
+pub struct ApplyAssignments {
+    pub assignments: Vec<GroupAssignment>,
+}
+
+// ===================================================================
+// Catechetical Year Models
+// ===================================================================
+
+#[derive(Deserialize)]
+pub struct CreateCatecheticalYear {
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Serialize)]
+pub struct CatecheticalYear {
+    pub id: i32,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub is_active: bool,
+}
+
+impl From<Row> for CatecheticalYear {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            name: row.get("name"),
+            start_date: row.get("start_date"),
+            end_date: row.get("end_date"),
+            is_active: row.get("is_active"),
+        }
+    }
+}
+
+// Query string accepted by list and statistics endpoints, e.g. `?year=3`.
+#[derive(Deserialize)]
+pub struct YearFilter {
+    pub year: Option<i32>,
+}
+
+// Body for `POST /api/years/rollover`.
+#[derive(Deserialize)]
+pub struct YearRollover {
+    #[serde(flatten)]
+    pub year: CreateCatecheticalYear,
+    // Re-create the outgoing year's groups (same module, catechist, weekday, time and room) in the new year.
+    #[serde(default)]
+    pub copy_groups: bool,
+}
+
+// ===================================================================
+// Confirmation Eligibility Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum RequirementKind {
+    Sacrament,
+    ModulesCompleted,
+    Sponsor,
+    MinimumAge,
+}
+
+// One entry of the configurable eligibility checklist. Which parameter is used depends on `kind`.
+#[derive(Deserialize)]
+pub struct CreateEligibilityRequirement {
+    pub kind: RequirementKind,
+    pub label: String,
+    pub sacrament_code: Option<String>,
+    pub module_count: Option<i16>,
+    pub minimum_age: Option<i16>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Serialize)]
+pub struct EligibilityRequirement {
+    pub id: i32,
+    pub kind: RequirementKind,
+    pub label: String,
+    pub sacrament_code: Option<String>,
+    pub module_count: Option<i16>,
+    pub minimum_age: Option<i16>,
+    pub enabled: bool,
+}
+
+impl From<Row> for EligibilityRequirement {
+    fn from(row: Row) -> Self {
+        let kind: String = row.get("kind");
+        Self {
+            id: row.get("id"),
+            // The column is a Postgres enum, so it always holds one of our variants.
+            kind: kind.parse().expect("unknown eligibility_requirement_kind_enum value"),
+            label: row.get("label"),
+            sacrament_code: row.get("sacrament_code"),
+            module_count: row.get("module_count"),
+            minimum_age: row.get("minimum_age"),
+            enabled: row.get("enabled"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub enum RequirementStatus {
+    Met,
+    NotMet,
+}
+
+#[derive(Serialize)]
+pub struct RequirementResult {
+    pub requirement_id: i32,
+    pub kind: RequirementKind,
+    pub label: String,
+    pub status: RequirementStatus,
+    pub detail: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct EligibilityReport {
+    pub confirmand_id: i32,
+    pub full_name: String,
+    pub eligible: bool,
+    pub requirements: Vec<RequirementResult>,
+}
+
+// ===================================================================
+// Sponsor Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum SponsorRelationship {
+    Godparent,
+    Grandparent,
+    Sibling,
+    #[strum(to_string = "Uncle/Aunt")]
+    #[serde(rename = "Uncle/Aunt")]
+    UncleAunt,
+    Cousin,
+    Friend,
+    Parent,
+    Other,
+}
+
+#[derive(Deserialize)]
+pub struct UpsertSponsor {
+    pub full_name: String,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub relationship: SponsorRelationship,
+    pub birth_date: NaiveDate,
+    pub is_confirmed: bool,
+    pub has_first_communion: bool,
+    pub marital_status: MaritalStatus,
+    pub parish: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Sponsor {
+    pub id: i32,
+    pub confirmand_id: i32,
+    pub full_name: String,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub relationship: String,
+    pub birth_date: NaiveDate,
+    pub is_confirmed: bool,
+    pub has_first_communion: bool,
+    pub marital_status: String,
+    pub parish: Option<String>,
+}
+
+impl From<Row> for Sponsor {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            confirmand_id: row.get("confirmand_id"),
+            full_name: row.get("full_name"),
+            email: row.get("email"),
+            phone_number: row.get("phone_number"),
+            relationship: row.get("relationship"),
+            birth_date: row.get("birth_date"),
+            is_confirmed: row.get("is_confirmed"),
+            has_first_communion: row.get("has_first_communion"),
+            marital_status: row.get("marital_status"),
+            parish: row.get("parish"),
+        }
+    }
+}
+
+// ===================================================================
+// Guardian Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum GuardianRelationship {
+    Father,
+    Mother,
+    #[strum(to_string = "Legal guardian")]
+    #[serde(rename = "Legal guardian")]
+    LegalGuardian,
+    Grandparent,
+    Sibling,
+    #[strum(to_string = "Uncle/Aunt")]
+    #[serde(rename = "Uncle/Aunt")]
+    UncleAunt,
+    Other,
+}
+
+// Links a guardian to a confirmand. Either refers to an existing guardian (e.g. a sibling's
+// parent) through `guardian_id`, or creates a new one from `full_name` and the contact data.
+#[derive(Deserialize)]
+pub struct LinkGuardian {
+    pub guardian_id: Option<i32>,
+    pub full_name: Option<String>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    pub relationship: GuardianRelationship,
+    #[serde(default)]
+    pub is_primary_contact: bool,
+    #[serde(default)]
+    pub is_consent_holder: bool,
+}
+
+// Updates both the guardian's contact data and their link to this confirmand.
+#[derive(Deserialize)]
+pub struct UpdateGuardian {
+    pub full_name: String,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    pub relationship: GuardianRelationship,
+    pub is_primary_contact: bool,
+    pub is_consent_holder: bool,
+}
+
+// A guardian as seen from one confirmand: the person plus the relationship and roles.
+#[derive(Serialize)]
+pub struct Guardian {
+    pub id: i32,
+    pub full_name: String,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    pub relationship: String,
+    pub is_primary_contact: bool,
+    pub is_consent_holder: bool,
+}
+
+impl From<Row> for Guardian {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            full_name: row.get("full_name"),
+            phone_number: row.get("phone_number"),
+            email: row.get("email"),
+            relationship: row.get("relationship"),
+            is_primary_contact: row.get("is_primary_contact"),
+            is_consent_holder: row.get("is_consent_holder"),
+        }
+    }
+}
+
+// ===================================================================
+// Consent Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum ConsentKind {
+    Participation,
+    #[strum(to_string = "Data processing")]
+    #[serde(rename = "Data processing")]
+    DataProcessing,
+    #[strum(to_string = "Photos and media")]
+    #[serde(rename = "Photos and media")]
+    PhotosAndMedia,
+    #[strum(to_string = "Medical treatment")]
+    #[serde(rename = "Medical treatment")]
+    MedicalTreatment,
+    Outings,
+}
+
+#[derive(Deserialize)]
+pub struct CreateConsent {
+    pub guardian_id: i32,
+    pub kind: ConsentKind,
+    pub given_date: NaiveDate,
+    pub document: Option<String>,
+}
+
+// Sent along with a minor's create/update: the guardian (new or existing) who gives the
+// participation consent. They are linked as consent holder.
+#[derive(Deserialize)]
+pub struct GuardianConsent {
+    #[serde(flatten)]
+    pub guardian: LinkGuardian,
+    pub given_date: NaiveDate,
+    pub document: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Consent {
+    pub id: i32,
+    pub confirmand_id: i32,
+    pub guardian_id: i32,
+    pub guardian_name: String,
+    pub kind: String,
+    pub given_date: NaiveDate,
+    pub document: Option<String>,
+}
+
+impl From<Row> for Consent {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            confirmand_id: row.get("confirmand_id"),
+            guardian_id: row.get("guardian_id"),
+            guardian_name: row.get("guardian_name"),
+            kind: row.get("kind"),
+            given_date: row.get("given_date"),
+            document: row.get("document"),
+        }
+    }
+}
+
+// ===================================================================
+// Emergency & Medical Information Models
+// ===================================================================
+
+// Stored encrypted; only returned to users whose role has been granted access.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct EmergencyInfo {
+    pub emergency_contact_name: Option<String>,
+    pub emergency_contact_phone: Option<String>,
+    pub allergies: Option<String>,
+    pub medical_notes: Option<String>,
+}
+
+impl From<&Row> for EmergencyInfo {
+    fn from(row: &Row) -> Self {
+        Self {
+            emergency_contact_name: row.get("emergency_contact_name"),
+            emergency_contact_phone: row.get("emergency_contact_phone"),
+            allergies: row.get("allergies"),
+            medical_notes: row.get("medical_notes"),
+        }
+    }
+}
+
+// ===================================================================
+// Household Models
+// ===================================================================
+
+#[derive(Deserialize)]
+pub struct CreateHousehold {
+    pub name: String,
+    pub address: Option<String>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Household {
+    pub id: i32,
+    pub name: String,
+    pub address: Option<String>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    pub confirmand_count: i64,
+}
+
+impl From<Row> for Household {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            name: row.get("name"),
+            address: row.get("address"),
+            phone_number: row.get("phone_number"),
+            email: row.get("email"),
+            confirmand_count: row.get("confirmand_count"),
+        }
+    }
+}
+
+// A confirmand or guardian belonging to a household.
+#[derive(Serialize)]
+pub struct HouseholdMember {
+    pub id: i32,
+    pub full_name: String,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+}
+
+impl From<Row> for HouseholdMember {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            full_name: row.get("full_name"),
+            phone_number: row.get("phone_number"),
+            email: row.get("email"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct HouseholdDetails {
+    #[serde(flatten)]
+    pub household: Household,
+    pub confirmands: Vec<HouseholdMember>,
+    pub guardians: Vec<HouseholdMember>,
+}
+
+#[derive(Deserialize)]
+pub struct AddHouseholdMembers {
+    #[serde(default)]
+    pub confirmand_ids: Vec<i32>,
+    #[serde(default)]
+    pub guardian_ids: Vec<i32>,
+}
+
+// One entry per family in a group, so that messages go out once per household.
+// Confirmands without a household are listed on their own.
+#[derive(Serialize)]
+pub struct FamilyContact {
+    pub household_id: Option<i32>,
+    pub name: String,
+    pub confirmand_names: Vec<String>,
+    pub contact_name: Option<String>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+}
+
+// ===================================================================
+// Confirmation Ceremony Models
+// ===================================================================
+
+#[derive(Deserialize)]
+pub struct CreateCeremony {
+    pub ceremony_date: NaiveDate,
+    pub church: String,
+    pub presiding_bishop: String,
+}
+
+#[derive(Serialize)]
+pub struct Ceremony {
+    pub id: i32,
+    pub ceremony_date: NaiveDate,
+    pub church: String,
+    pub presiding_bishop: String,
+    pub finalized_at: Option<DateTime<Utc>>,
+    pub candidate_count: i64,
+}
+
+impl From<Row> for Ceremony {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            ceremony_date: row.get("ceremony_date"),
+            church: row.get("church"),
+            presiding_bishop: row.get("presiding_bishop"),
+            finalized_at: row.get("finalized_at"),
+            candidate_count: row.get("candidate_count"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CeremonyCandidate {
+    pub confirmand_id: i32,
+    pub full_name: String,
+    pub birth_date: NaiveDate,
+    pub sponsor_name: Option<String>,
+    pub attended: bool,
+}
+
+impl From<Row> for CeremonyCandidate {
+    fn from(row: Row) -> Self {
+        Self {
+            confirmand_id: row.get("confirmand_id"),
+            full_name: row.get("full_name"),
+            birth_date: row.get("birth_date"),
+            sponsor_name: row.get("sponsor_name"),
+            attended: row.get("attended"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CeremonyDetails {
+    #[serde(flatten)]
+    pub ceremony: Ceremony,
+    pub candidates: Vec<CeremonyCandidate>,
+}
+
+#[derive(Deserialize)]
+pub struct AddCeremonyCandidates {
+    pub confirmand_ids: Vec<i32>,
+}
+
+// Everyone on the candidate list is assumed to have attended unless listed here.
+// Send `{}` when nobody was absent.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FinalizeCeremony {
+    #[serde(default)]
+    pub absent_confirmand_ids: Vec<i32>,
+}
+
+// ===================================================================
+// Certificate Models
+// ===================================================================
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum CertificateKind {
+    Module,
+    Confirmation,
+}
+
+// The parish header and signature printed on every certificate.
+#[derive(Serialize, Deserialize)]
+pub struct CertificateSettings {
+    pub parish_name: String,
+    pub diocese: Option<String>,
+    pub address: Option<String>,
+    pub signatory_name: Option<String>,
+    pub signatory_title: Option<String>,
+}
+
+impl From<Row> for CertificateSettings {
+    fn from(row: Row) -> Self {
+        Self {
+            parish_name: row.get("parish_name"),
+            diocese: row.get("diocese"),
+            address: row.get("address"),
+            signatory_name: row.get("signatory_name"),
+            signatory_title: row.get("signatory_title"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CertificateTemplate {
+    pub kind: CertificateKind,
+    pub title: String,
+    pub body: String,
+}
+
+impl From<Row> for CertificateTemplate {
+    fn from(row: Row) -> Self {
+        let kind: String = row.get("kind");
+        Self {
+            kind: kind.parse().expect("unknown certificate_kind_enum value"),
+            title: row.get("title"),
+            body: row.get("body"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UpdateCertificateTemplate {
+    pub title: String,
+    pub body: String,
+}
+
+// ===================================================================
+// Audit Log Models
+// ===================================================================
+
+// Query string for `GET /api/audit`, e.g. `?entity=confirmand&id=12`.
+#[derive(Deserialize)]
+pub struct AuditQuery {
+    pub entity: Option<String>,
+    pub id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, EnumString, Clone, PartialEq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+// One row change. Updates carry only the columns that changed, in `before` and `after`.
+#[derive(Serialize)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub user_id: Option<i32>,
+    pub username: Option<String>,
+    pub entity: String,
+    pub entity_id: Option<i32>,
+    pub confirmand_id: Option<i32>,
+    pub action: AuditAction,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<Row> for AuditEntry {
+    fn from(row: Row) -> Self {
+        let action: String = row.get("action");
+        Self {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            username: row.get("username"),
+            entity: row.get("entity"),
+            entity_id: row.get("entity_id"),
+            confirmand_id: row.get("confirmand_id"),
+            action: action.parse().expect("unknown audit_action_enum value"),
+            before: row.get("before"),
+            after: row.get("after"),
+            changed_at: row.get("changed_at"),
+        }
+    }
+}
+
+// ===================================================================
+// Participant History Models
+// ===================================================================
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub enum HistoryEventKind {
+    Registered,
+    FieldsChanged,
+    Archived,
+    Restored,
+    Enrolled,
+    Transferred,
+    LeftGroup,
+    ModuleCompleted,
+    SacramentReceived,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+// One entry of `GET /api/confirmands/:id/history`. `recorded_at` is only known for changes made
+// since the audit log exists; events derived from dates alone (a group's start, a sacrament) have
+// just `date`.
+#[derive(Serialize, Debug)]
+pub struct HistoryEvent {
+    pub date: NaiveDate,
+    pub recorded_at: Option<DateTime<Utc>>,
+    pub kind: HistoryEventKind,
+    pub summary: String,
+    pub changes: Vec<FieldChange>,
+    pub username: Option<String>,
+}
+
+// ===================================================================
+// Duplicate Detection Models
+// ===================================================================
+
+// Two participants who are probably the same person. `score` runs from 0.7 to 1.0.
+#[derive(Serialize)]
+pub struct DuplicateCandidate {
+    pub first: Confirmand,
+    pub second: Confirmand,
+    pub score: f64,
+    pub reasons: Vec<String>,
+}
+
+// Body for `POST /api/confirmands/:id/merge`. The participant in the path survives.
+#[derive(Deserialize)]
+pub struct MergeConfirmands {
+    pub duplicate_id: i32,
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use chrono::NaiveDate;
+use printpdf::{BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+use crate::certificates::{format_date, rule};
+use crate::models::{Confirmand, ConfirmationGroupDetails, EmergencyInfo};
+
+// A4 landscape, in millimetres.
+const PAGE_WIDTH: f32 = 297.0;
+const PAGE_HEIGHT: f32 = 210.0;
+const MARGIN: f32 = 15.0;
+const ROW_HEIGHT: f32 = 8.0;
+// Member rows that fit below the page header.
+const ROWS_PER_PAGE: usize = 18;
+// Session columns on one attendance sheet; longer schedules continue on the next sheet.
+const SESSIONS_PER_PAGE: usize = 10;
+const NAME_COLUMN_WIDTH: f32 = 70.0;
+
+// One line per member, with the same date format the confirmand import expects. Emergency
+// columns are only added when `emergency` is given.
+pub fn to_csv(members: &[Confirmand], emergency: Option<&HashMap<i32, EmergencyInfo>>) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let mut headers = vec!["full_name", "birth_date", "phone_number", "email", "father_name", "mother_name"];
+    if emergency.is_some() {
+        headers.extend(["emergency_contact_name", "emergency_contact_phone", "allergies"]);
+    }
+    writer.write_record(&headers)?;
+    for member in members {
+        let mut record = vec![
+            member.full_name.clone(),
+            format_date(member.birth_date),
+            member.phone_number.clone(),
+            member.email.clone(),
+            member.father_name.clone().unwrap_or_default(),
+            member.mother_name.clone().unwrap_or_default(),
+        ];
+        if let Some(emergency) = emergency {
+            let info = emergency.get(&member.id).cloned().unwrap_or_default();
+            record.extend([
+                info.emergency_contact_name.unwrap_or_default(),
+                info.emergency_contact_phone.unwrap_or_default(),
+                info.allergies.unwrap_or_default(),
+            ]);
+        }
+        writer.write_record(&record)?;
+    }
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+// Renders the member list followed by a blank attendance sheet for the given sessions. The member
+// list gets emergency contact and allergy columns only when `emergency` is given.
+pub fn render(
+    parish_name: &str,
+    group: &ConfirmationGroupDetails,
+    emergency: Option<&HashMap<i32, EmergencyInfo>>,
+    sessions: &[NaiveDate],
+) -> Result<Vec<u8>, printpdf::Error> {
+    let title = format!("Module {} - {}", group.module, group.day_of_the_week);
+    let (doc, page, layer) = PdfDocument::new(&title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Roster");
+    let regular = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+    let mut layer = doc.get_page(page).get_layer(layer);
+    let new_page = || {
+        let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Roster");
+        doc.get_page(page).get_layer(layer)
+    };
+
+    // Member list: name, birth date, phone and parents. An empty group still gets its first page.
+    let columns: &[(&str, f32)] = match emergency {
+        None => &[("Name", MARGIN), ("Birth date", 85.0), ("Phone", 110.0), ("Father", 150.0), ("Mother", 220.0)],
+        Some(_) => &[
+            ("Name", MARGIN),
+            ("Birth date", 70.0),
+            ("Phone", 92.0),
+            ("Father", 122.0),
+            ("Mother", 157.0),
+            ("Emergency contact", 192.0),
+            ("Allergies", 242.0),
+        ],
+    };
+    let mut member_pages: Vec<&[Confirmand]> = group.members.chunks(ROWS_PER_PAGE).collect();
+    if member_pages.is_empty() {
+        member_pages.push(&[]);
+    }
+    for (index, members) in member_pages.iter().enumerate() {
+        if index > 0 {
+            layer = new_page();
+        }
+        let mut y = header(&layer, &bold, &regular, parish_name, &title, group, "Members");
+        for &(name, x) in columns {
+            layer.use_text(name, 10.0, Mm(x), Mm(y), &bold);
+        }
+        for member in members.iter() {
+            rule(&layer, y - 2.5, MARGIN, PAGE_WIDTH - MARGIN);
+            y -= ROW_HEIGHT;
+            let mut cells = vec![
+                member.full_name.clone(),
+                format_date(member.birth_date),
+                member.phone_number.clone(),
+                member.father_name.clone().unwrap_or_default(),
+                member.mother_name.clone().unwrap_or_default(),
+            ];
+            if let Some(emergency) = emergency {
+                let info = emergency.get(&member.id).cloned().unwrap_or_default();
+                let contact = [info.emergency_contact_name, info.emergency_contact_phone];
+                cells.push(contact.into_iter().flatten().collect::<Vec<_>>().join(" "));
+                cells.push(info.allergies.unwrap_or_default());
+            }
+            for ((_, x), cell) in columns.iter().zip(cells) {
+                layer.use_text(cell, 10.0, Mm(*x), Mm(y), &regular);
+            }
+        }
+    }
+
+    // Attendance sheet: one row per member, one empty box per upcoming session.
+    let session_width = (PAGE_WIDTH - 2.0 * MARGIN - NAME_COLUMN_WIDTH) / SESSIONS_PER_PAGE as f32;
+    for dates in sessions.chunks(SESSIONS_PER_PAGE) {
+        for members in &member_pages {
+            layer = new_page();
+            let top = header(&layer, &bold, &regular, parish_name, &title, group, "Attendance");
+            layer.use_text("Name", 10.0, Mm(MARGIN), Mm(top), &bold);
+            for (column, date) in dates.iter().enumerate() {
+                let x = MARGIN + NAME_COLUMN_WIDTH + column as f32 * session_width;
+                layer.use_text(date.format("%d/%m").to_string(), 10.0, Mm(x + 2.0), Mm(top), &bold);
+            }
+
+            let mut y = top;
+            for member in members.iter() {
+                rule(&layer, y - 2.5, MARGIN, PAGE_WIDTH - MARGIN);
+                y -= ROW_HEIGHT;
+                layer.use_text(member.full_name.as_str(), 10.0, Mm(MARGIN), Mm(y), &regular);
+            }
+            rule(&layer, y - 2.5, MARGIN, PAGE_WIDTH - MARGIN);
+            for column in 0..=dates.len() {
+                let x = MARGIN + NAME_COLUMN_WIDTH + column as f32 * session_width;
+                vertical_rule(&layer, x, top + ROW_HEIGHT - 2.5, y - 2.5);
+            }
+        }
+    }
+
+    doc.save_to_bytes()
+}
+
+// Writes the parish, group and section title and returns the y position of the first table row.
+fn header(
+    layer: &PdfLayerReference,
+    bold: &IndirectFontRef,
+    regular: &IndirectFontRef,
+    parish_name: &str,
+    title: &str,
+    group: &ConfirmationGroupDetails,
+    section: &str,
+) -> f32 {
+    let mut y = PAGE_HEIGHT - MARGIN;
+    layer.use_text(parish_name, 10.0, Mm(MARGIN), Mm(y), regular);
+    y -= 8.0;
+    layer.use_text(format!("{} - {}", title, section), 16.0, Mm(MARGIN), Mm(y), bold);
+    y -= 6.0;
+    let mut info = Vec::new();
+    if let Some(name) = &group.catechist_name {
+        info.push(format!("Catechist: {}", name));
+    }
+    if let (Some(start), Some(end)) = (group.start_time, group.end_time) {
+        info.push(format!("{} - {}", start.format("%H:%M"), end.format("%H:%M")));
+    }
+    if let Some(room) = &group.room_name {
+        info.push(format!("Room: {}", room));
+    }
+    layer.use_text(info.join("   "), 10.0, Mm(MARGIN), Mm(y), regular);
+    y - 12.0
+}
+
+fn vertical_rule(layer: &PdfLayerReference, x: f32, from_y: f32, to_y: f32) {
+    layer.set_outline_thickness(0.5);
+    layer.add_line(Line {
+        points: vec![(Point::new(Mm(x), Mm(from_y)), false), (Point::new(Mm(x), Mm(to_y)), false)],
+        is_closed: false,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn members() -> Vec<Confirmand> {
+        vec![
+            Confirmand {
+                phone_number: "912345678".to_string(),
+                email: "ana@example.pt".to_string(),
+                father_name: Some("Rui Silva".to_string()),
+                ..Confirmand::sample(1, "Ana Silva", date(2010, 3, 4))
+            },
+            Confirmand::sample(2, "Tiago Costa", date(2011, 7, 1)),
+        ]
+    }
+
+    fn lines(csv: Vec<u8>) -> Vec<String> {
+        String::from_utf8(csv).unwrap().lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn writes_one_csv_row_per_member() {
+        assert_eq!(
+            lines(to_csv(&members(), None).unwrap()),
+            vec![
+                "full_name,birth_date,phone_number,email,father_name,mother_name",
+                "Ana Silva,04/03/2010,912345678,ana@example.pt,Rui Silva,",
+                "Tiago Costa,01/07/2011,,,,",
+            ]
+        );
+    }
+
+    #[test]
+    fn adds_emergency_columns_only_when_given() {
+        let info = EmergencyInfo {
+            emergency_contact_name: Some("Rui Silva".to_string()),
+            emergency_contact_phone: Some("913000000".to_string()),
+            allergies: Some("Peanuts".to_string()),
+            medical_notes: Some("Not exported".to_string()),
+        };
+        let emergency = HashMap::from([(1, info)]);
+        assert_eq!(
+            lines(to_csv(&members(), Some(&emergency)).unwrap()),
+            vec![
+                "full_name,birth_date,phone_number,email,father_name,mother_name,emergency_contact_name,emergency_contact_phone,allergies",
+                "Ana Silva,04/03/2010,912345678,ana@example.pt,Rui Silva,,Rui Silva,913000000,Peanuts",
+                "Tiago Costa,01/07/2011,,,,,,,",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_an_empty_group() {
+        let group = ConfirmationGroupDetails {
+            id: 1,
+            module: 1,
+            catechist_name: None,
+            day_of_the_week: "Saturday".to_string(),
+            start_date: date(2026, 10, 3),
+            start_time: None,
+            end_time: None,
+            room_id: None,
+            room_name: None,
+            version: 1,
+            session_rooms: Vec::new(),
+            members: Vec::new(),
+        };
+        let pdf = render("Paróquia da Sé", &group, None, &[date(2026, 10, 3), date(2026, 10, 10)]).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use crate::models::{GroupSchedule, HolidayKind, ParishHoliday, SkippedSession};
+
+// Groups without an `end_date` still need a finite schedule, so we plan this far ahead of today
+// (or of the start date, for groups that have not started yet).
+const OPEN_ENDED_HORIZON_DAYS: i64 = 365;
+
+// Computes Easter Sunday for a Gregorian year (anonymous Gregorian algorithm).
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).expect("Easter is always a valid date")
+}
+
+pub fn first_weekday_on_or_after(date: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let offset = (7 + weekday.num_days_from_monday() as i64 - date.weekday().num_days_from_monday() as i64) % 7;
+    date + Duration::days(offset)
+}
+
+// The last date we plan sessions for, given a group's (optional) end date.
+pub fn schedule_end(start_date: NaiveDate, end_date: Option<NaiveDate>, today: NaiveDate) -> NaiveDate {
+    end_date.unwrap_or(start_date.max(today) + Duration::days(OPEN_ENDED_HORIZON_DAYS))
+}
+
+// Returns true if the holiday falls on the given date.
+pub fn holiday_covers(holiday: &ParishHoliday, date: NaiveDate) -> bool {
+    match holiday.kind {
+        HolidayKind::Range => match (holiday.start_date, holiday.end_date) {
+            (Some(start), Some(end)) => start <= date && date <= end,
+            _ => false,
+        },
+        HolidayKind::Annual => match (holiday.start_date, holiday.end_date) {
+            (Some(start), Some(end)) => {
+                let day = (date.month(), date.day());
+                let from = (start.month(), start.day());
+                let to = (end.month(), end.day());
+                if from <= to {
+                    from <= day && day <= to
+                } else {
+                    // The range wraps around the new year.
+                    day >= from || day <= to
+                }
+            }
+            _ => false,
+        },
+        HolidayKind::EasterRelative => match (holiday.easter_offset_start, holiday.easter_offset_end) {
+            (Some(from), Some(to)) => {
+                let offset = (date - easter_sunday(date.year())).num_days();
+                from as i64 <= offset && offset <= to as i64
+            }
+            _ => false,
+        },
+    }
+}
+
+pub fn matching_holiday(holidays: &[ParishHoliday], date: NaiveDate) -> Option<&ParishHoliday> {
+    holidays.iter().find(|h| holiday_covers(h, date))
+}
+
+// Every weekly meeting date between the start and (planned) end of a group, ignoring holidays.
+pub fn candidate_dates(weekday: Weekday, start_date: NaiveDate, end_date: Option<NaiveDate>, today: NaiveDate) -> Vec<NaiveDate> {
+    let last = schedule_end(start_date, end_date, today);
+    let mut dates = Vec::new();
+    let mut date = first_weekday_on_or_after(start_date, weekday);
+    while date <= last {
+        dates.push(date);
+        date += Duration::weeks(1);
+    }
+    dates
+}
+
+// Splits a group's weekly meeting dates into actual sessions and those cancelled by a holiday.
+pub fn plan_sessions(
+    group_id: i32,
+    weekday: Weekday,
+    start_date: NaiveDate,
+    end_date: Option<NaiveDate>,
+    today: NaiveDate,
+    holidays: &[ParishHoliday],
+) -> GroupSchedule {
+    let mut sessions = Vec::new();
+    let mut skipped = Vec::new();
+    for date in candidate_dates(weekday, start_date, end_date, today) {
+        match matching_holiday(holidays, date) {
+            Some(holiday) => skipped.push(SkippedSession {
+                date,
+                holiday_id: holiday.id,
+                holiday_name: holiday.name.clone(),
+            }),
+            None => sessions.push(date),
+        }
+    }
+    GroupSchedule { group_id, sessions, skipped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn holiday(kind: HolidayKind, dates: Option<(NaiveDate, NaiveDate)>, offsets: Option<(i32, i32)>) -> ParishHoliday {
+        ParishHoliday {
+            id: 1,
+            name: "Holiday".to_string(),
+            kind,
+            start_date: dates.map(|(start, _)| start),
+            end_date: dates.map(|(_, end)| end),
+            easter_offset_start: offsets.map(|(start, _)| start),
+            easter_offset_end: offsets.map(|(_, end)| end),
+        }
+    }
+
+    #[test]
+    fn easter_sunday_matches_known_dates() {
+        assert_eq!(easter_sunday(2008), date(2008, 3, 23));
+        assert_eq!(easter_sunday(2019), date(2019, 4, 21));
+        assert_eq!(easter_sunday(2024), date(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), date(2025, 4, 20));
+        assert_eq!(easter_sunday(2038), date(2038, 4, 25));
+        assert_eq!(easter_sunday(2000), date(2000, 4, 23));
+    }
+
+    #[test]
+    fn range_holiday_includes_both_ends() {
+        let christmas = holiday(HolidayKind::Range, Some((date(2025, 12, 20), date(2026, 1, 4))), None);
+        assert!(holiday_covers(&christmas, date(2025, 12, 20)));
+        assert!(holiday_covers(&christmas, date(2026, 1, 4)));
+        assert!(!holiday_covers(&christmas, date(2026, 1, 5)));
+        assert!(!holiday_covers(&christmas, date(2024, 12, 25)));
+    }
+
+    #[test]
+    fn annual_holiday_repeats_every_year_and_wraps_around_new_year() {
+        let summer = holiday(HolidayKind::Annual, Some((date(2000, 7, 1), date(2000, 8, 31))), None);
+        assert!(holiday_covers(&summer, date(2031, 7, 15)));
+        assert!(!holiday_covers(&summer, date(2031, 9, 1)));
+
+        let christmas = holiday(HolidayKind::Annual, Some((date(2000, 12, 24), date(2000, 1, 6))), None);
+        assert!(holiday_covers(&christmas, date(2030, 12, 31)));
+        assert!(holiday_covers(&christmas, date(2031, 1, 6)));
+        assert!(!holiday_covers(&christmas, date(2031, 1, 7)));
+        assert!(!holiday_covers(&christmas, date(2030, 12, 23)));
+    }
+
+    #[test]
+    fn easter_relative_holiday_follows_easter() {
+        // Holy Week: Palm Sunday to Easter Monday.
+        let holy_week = holiday(HolidayKind::EasterRelative, None, Some((-7, 1)));
+        assert!(holiday_covers(&holy_week, date(2025, 4, 13)));
+        assert!(holiday_covers(&holy_week, date(2025, 4, 21)));
+        assert!(!holiday_covers(&holy_week, date(2025, 4, 22)));
+        assert!(holiday_covers(&holy_week, date(2024, 3, 24)));
+        assert!(!holiday_covers(&holy_week, date(2024, 4, 13)));
+    }
+
+    #[test]
+    fn holiday_without_its_dates_covers_nothing() {
+        let incomplete = holiday(HolidayKind::Range, None, None);
+        assert!(!holiday_covers(&incomplete, date(2025, 1, 1)));
+    }
+
+    #[test]
+    fn plan_sessions_skips_holidays() {
+        let holidays = vec![holiday(HolidayKind::Range, Some((date(2025, 10, 8), date(2025, 10, 8))), None)];
+        // 2025-10-01 is a Wednesday.
+        let schedule = plan_sessions(7, Weekday::Wed, date(2025, 9, 29), Some(date(2025, 10, 15)), date(2025, 9, 1), &holidays);
+        assert_eq!(schedule.sessions, vec![date(2025, 10, 1), date(2025, 10, 15)]);
+        assert_eq!(schedule.skipped.len(), 1);
+        assert_eq!(schedule.skipped[0].date, date(2025, 10, 8));
+    }
+
+    #[test]
+    fn open_ended_groups_are_planned_a_year_ahead_of_today() {
+        let start = date(2020, 1, 6);
+        assert_eq!(schedule_end(start, None, date(2019, 12, 1)), date(2021, 1, 5));
+        assert_eq!(schedule_end(start, None, date(2025, 9, 1)), date(2026, 9, 1));
+        assert_eq!(schedule_end(start, Some(date(2020, 6, 30)), date(2025, 9, 1)), date(2020, 6, 30));
+    }
+}
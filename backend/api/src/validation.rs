@@ -0,0 +1,663 @@
+use std::collections::BTreeMap;
+use axum::{
+    body::{self, Body},
+    http::{header, HeaderValue, StatusCode},
+    response::Response,
+};
+use chrono::NaiveDate;
+use serde_json::json;
+use crate::{
+    assignment::age_on,
+    models::{
+        CatechistAvailability, CertificateSettings, CreateCatecheticalYear, CreateCatechist, CreateCeremony, CreateConfirmand,
+        CreateConfirmationGroup, CreateConsent, CreateEligibilityRequirement, CreateHousehold, CreateLocation,
+        CreateParishHoliday, CreateRoom, HolidayKind, LinkGuardian, MaritalStatus, PatchConfirmand, ProposeAssignments,
+        RequirementKind, SponsorRelationship, UpdateCertificateTemplate, UpdateGuardian, UpsertSponsor,
+    },
+};
+
+// A birth date further back than this is a typo.
+const MAX_AGE: i32 = 120;
+const MAX_EMAIL_LENGTH: usize = 254;
+const PORTUGAL_COUNTRY_CODE: &str = "351";
+// Canon 874 §1, 2°: a sponsor must have completed their sixteenth year.
+const SPONSOR_MINIMUM_AGE: i32 = 16;
+
+// Every problem found in a payload, by field. Sent back as `422 Unprocessable Entity` with a body
+// like `{"errors": {"email": ["..."], "phone_number": ["..."]}}`, labelled as JSON by
+// `json_field_errors`.
+#[derive(Default)]
+pub struct FieldErrors(BTreeMap<String, Vec<String>>);
+
+impl FieldErrors {
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.0.entry(field.into()).or_default().push(message.into());
+    }
+
+    pub fn into_result(self) -> Result<(), (StatusCode, String)> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err((StatusCode::UNPROCESSABLE_ENTITY, json!({ "errors": self.0 }).to_string()))
+        }
+    }
+}
+
+// Handlers fail with `(StatusCode, String)`, which axum sends as `text/plain`. Applied to the whole
+// router, this gives field errors their `application/json` content type. Other 422 responses, such
+// as a body that does not deserialize, are left as they are.
+pub async fn json_field_errors(response: Response) -> Response {
+    if response.status() != StatusCode::UNPROCESSABLE_ENTITY {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = body::to_bytes(body, usize::MAX).await else {
+        return Response::from_parts(parts, Body::empty());
+    };
+    let is_field_errors = serde_json::from_slice::<serde_json::Value>(&bytes).is_ok_and(|value| value.get("errors").is_some());
+    if is_field_errors {
+        parts.headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+// Trims and normalizes a create/update payload in place, then checks it.
+pub trait Validate {
+    fn validate(&mut self, today: NaiveDate) -> Result<(), (StatusCode, String)>;
+}
+
+impl Validate for CreateConfirmand {
+    fn validate(&mut self, today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "full_name", &mut self.full_name);
+        required(&mut errors, "address", &mut self.address);
+        email(&mut errors, "email", &mut self.email);
+        phone(&mut errors, "phone_number", &mut self.phone_number);
+        birth_date(&mut errors, "birth_date", self.birth_date, today);
+        for value in [&mut self.father_name, &mut self.mother_name, &mut self.baptism_church, &mut self.communion_church] {
+            trim_optional(value);
+        }
+        if let Some(consent) = &mut self.guardian_consent {
+            guardian(&mut errors, "guardian_consent", &mut consent.guardian);
+            if consent.given_date > today {
+                errors.add("guardian_consent.given_date", "cannot be in the future");
+            }
+            trim_optional(&mut consent.document);
+        }
+        errors.into_result()
+    }
+}
+
+// Only refuses `null` for fields every participant must have; the merged record is validated as a
+// `CreateConfirmand` once the patch is applied.
+impl Validate for PatchConfirmand {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        let nulls = [
+            ("full_name", matches!(self.full_name, Some(None))),
+            ("birth_date", matches!(self.birth_date, Some(None))),
+            ("address", matches!(self.address, Some(None))),
+            ("phone_number", matches!(self.phone_number, Some(None))),
+            ("email", matches!(self.email, Some(None))),
+            ("marital_status", matches!(self.marital_status, Some(None))),
+        ];
+        for (field, is_null) in nulls {
+            if is_null {
+                errors.add(field, "cannot be null");
+            }
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateCatechist {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "full_name", &mut self.full_name);
+        if self.max_concurrent_groups.is_some_and(|max| max < 1) {
+            errors.add("max_concurrent_groups", "must be at least 1");
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateConfirmationGroup {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        if self.module < 1 {
+            errors.add("module", "must be at least 1");
+        }
+        trim_optional(&mut self.group_link);
+        if self.end_date.is_some_and(|end| end < self.start_date) {
+            errors.add("end_date", "cannot be before start_date");
+        }
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time)
+            && start >= end
+        {
+            errors.add("end_time", "must be after start_time");
+        }
+        errors.into_result()
+    }
+}
+
+// The canonical requirements for a sponsor (canon 874).
+impl Validate for UpsertSponsor {
+    fn validate(&mut self, today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "full_name", &mut self.full_name);
+        let age = age_on(self.birth_date, today);
+        if age < SPONSOR_MINIMUM_AGE {
+            errors.add("birth_date", format!("must make the sponsor at least {} years old (is {})", SPONSOR_MINIMUM_AGE, age));
+        }
+        if !self.is_confirmed {
+            errors.add("is_confirmed", "the sponsor must be confirmed");
+        }
+        if !self.has_first_communion {
+            errors.add("has_first_communion", "the sponsor must have received First Communion");
+        }
+        if self.relationship == SponsorRelationship::Parent {
+            errors.add("relationship", "a parent cannot be the sponsor");
+        }
+        if matches!(self.marital_status, MaritalStatus::MarriedCivil | MaritalStatus::Union) {
+            errors.add("marital_status", format!("'{}' cannot be accepted for a sponsor", self.marital_status));
+        }
+        for value in [&mut self.email, &mut self.phone_number, &mut self.parish] {
+            trim_optional(value);
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateParishHoliday {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "name", &mut self.name);
+        let requirement = format!("is required for {} holidays", self.kind);
+        match self.kind {
+            HolidayKind::Range => bounds(&mut errors, ("start_date", self.start_date), ("end_date", self.end_date), true, &requirement),
+            // Annual ranges may wrap around the new year, so their order is not checked.
+            HolidayKind::Annual => bounds(&mut errors, ("start_date", self.start_date), ("end_date", self.end_date), false, &requirement),
+            HolidayKind::EasterRelative => bounds(
+                &mut errors,
+                ("easter_offset_start", self.easter_offset_start),
+                ("easter_offset_end", self.easter_offset_end),
+                true,
+                &requirement,
+            ),
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateLocation {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "name", &mut self.name);
+        trim_optional(&mut self.address);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateRoom {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "name", &mut self.name);
+        if self.capacity.is_some_and(|capacity| capacity < 1) {
+            errors.add("capacity", "must be at least 1");
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CatechistAvailability {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        if self.max_concurrent_groups.is_some_and(|max| max < 0) {
+            errors.add("max_concurrent_groups", "cannot be negative");
+        }
+        for (index, slot) in self.slots.iter().enumerate() {
+            if slot.start_time >= slot.end_time {
+                errors.add(format!("slots[{}].end_time", index), "must be after start_time");
+            }
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for ProposeAssignments {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        if self.target_group_size < 1 {
+            errors.add("target_group_size", "must be at least 1");
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateCatecheticalYear {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "name", &mut self.name);
+        if self.end_date <= self.start_date {
+            errors.add("end_date", "must be after start_date");
+        }
+        errors.into_result()
+    }
+}
+
+// The whole checklist is sent at once, so errors are keyed by position, e.g. `[2].module_count`.
+impl Validate for Vec<CreateEligibilityRequirement> {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        for (index, requirement) in self.iter_mut().enumerate() {
+            required(&mut errors, &format!("[{}].label", index), &mut requirement.label);
+            let (field, valid) = match requirement.kind {
+                RequirementKind::Sacrament => ("sacrament_code", requirement.sacrament_code.is_some()),
+                RequirementKind::ModulesCompleted => ("module_count", requirement.module_count.is_some_and(|n| n > 0)),
+                RequirementKind::MinimumAge => ("minimum_age", requirement.minimum_age.is_some_and(|n| n >= 0)),
+                RequirementKind::Sponsor => ("", true),
+            };
+            if !valid {
+                errors.add(format!("[{}].{}", index, field), format!("is required for {} requirements", requirement.kind));
+            }
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateCeremony {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "church", &mut self.church);
+        required(&mut errors, "presiding_bishop", &mut self.presiding_bishop);
+        errors.into_result()
+    }
+}
+
+impl Validate for CertificateSettings {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "parish_name", &mut self.parish_name);
+        for value in [&mut self.diocese, &mut self.address, &mut self.signatory_name, &mut self.signatory_title] {
+            trim_optional(value);
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateCertificateTemplate {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "title", &mut self.title);
+        // The body keeps its layout; it only has to say something.
+        if self.body.trim().is_empty() {
+            errors.add("body", "is required");
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for LinkGuardian {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        guardian(&mut errors, "", self);
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateGuardian {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "full_name", &mut self.full_name);
+        optional_contact(&mut errors, "", &mut self.email, &mut self.phone_number);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateConsent {
+    fn validate(&mut self, today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        if self.given_date > today {
+            errors.add("given_date", "cannot be in the future");
+        }
+        trim_optional(&mut self.document);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateHousehold {
+    fn validate(&mut self, _today: NaiveDate) -> Result<(), (StatusCode, String)> {
+        let mut errors = FieldErrors::default();
+        required(&mut errors, "name", &mut self.name);
+        if self.address.as_deref().is_some_and(|address| address.trim().is_empty()) {
+            errors.add("address", "cannot be blank; leave it out instead");
+        }
+        errors.into_result()
+    }
+}
+
+// A new guardian sent inline needs a name; contact data is optional but must be valid. `prefix` is
+// the guardian's field in the payload, or empty when the guardian is the payload.
+fn guardian(errors: &mut FieldErrors, prefix: &str, guardian: &mut LinkGuardian) {
+    trim_optional(&mut guardian.full_name);
+    if guardian.guardian_id.is_none() && guardian.full_name.is_none() {
+        errors.add(nested(prefix, "full_name"), "is required unless guardian_id is given");
+    }
+    optional_contact(errors, prefix, &mut guardian.email, &mut guardian.phone_number);
+}
+
+fn optional_contact(errors: &mut FieldErrors, prefix: &str, email_value: &mut Option<String>, phone_value: &mut Option<String>) {
+    trim_optional(email_value);
+    if let Some(value) = email_value {
+        email(errors, &nested(prefix, "email"), value);
+    }
+    trim_optional(phone_value);
+    if let Some(value) = phone_value {
+        phone(errors, &nested(prefix, "phone_number"), value);
+    }
+}
+
+// Both ends of a range are required and, when `ordered`, the end cannot come before the start.
+fn bounds<T: PartialOrd>(
+    errors: &mut FieldErrors,
+    (start_field, start): (&str, Option<T>),
+    (end_field, end): (&str, Option<T>),
+    ordered: bool,
+    requirement: &str,
+) {
+    match (start, end) {
+        (Some(start), Some(end)) if ordered && start > end => errors.add(end_field, format!("cannot be before {}", start_field)),
+        (Some(_), Some(_)) => {}
+        (start, end) => {
+            if start.is_none() {
+                errors.add(start_field, requirement);
+            }
+            if end.is_none() {
+                errors.add(end_field, requirement);
+            }
+        }
+    }
+}
+
+fn nested(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() { field.to_string() } else { format!("{}.{}", prefix, field) }
+}
+
+fn required(errors: &mut FieldErrors, field: &str, value: &mut String) {
+    *value = value.trim().to_string();
+    if value.is_empty() {
+        errors.add(field, "is required");
+    }
+}
+
+// Blank optional values are stored as NULL.
+fn trim_optional(value: &mut Option<String>) {
+    *value = value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+}
+
+fn email(errors: &mut FieldErrors, field: &str, value: &mut String) {
+    *value = value.trim().to_string();
+    if value.is_empty() {
+        errors.add(field, "is required");
+    } else if !is_valid_email(value) {
+        errors.add(field, format!("'{}' is not a valid email address", value));
+    }
+}
+
+fn phone(errors: &mut FieldErrors, field: &str, value: &mut String) {
+    if value.trim().is_empty() {
+        errors.add(field, "is required");
+        return;
+    }
+    match normalize_phone(value) {
+        Ok(normalized) => *value = normalized,
+        Err(message) => errors.add(field, message),
+    }
+}
+
+fn birth_date(errors: &mut FieldErrors, field: &str, date: NaiveDate, today: NaiveDate) {
+    if date > today {
+        errors.add(field, "cannot be in the future");
+    } else if age_on(date, today) > MAX_AGE {
+        errors.add(field, format!("is more than {} years ago", MAX_AGE));
+    }
+}
+
+// A pragmatic syntax check: one `@`, no whitespace, and a domain with at least one dot between
+// non-empty labels.
+pub fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    email.len() <= MAX_EMAIL_LENGTH
+        && !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && !label.starts_with('-') && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+// Normalizes a phone number to E.164 (`+351912345678`). Numbers without a country code are taken
+// to be Portuguese; international numbers need a leading `+` or `00`.
+pub fn normalize_phone(phone: &str) -> Result<String, String> {
+    let invalid = || format!("'{}' is not a valid phone number", phone.trim());
+    let compact: String = phone.chars().filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')')).collect();
+    let (international, digits) = if let Some(rest) = compact.strip_prefix('+') {
+        (true, rest)
+    } else if let Some(rest) = compact.strip_prefix("00") {
+        (true, rest)
+    } else {
+        (false, compact.as_str())
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let national = if international {
+        match digits.strip_prefix(PORTUGAL_COUNTRY_CODE) {
+            Some(national) => national,
+            // E.164 allows at most 15 digits, country code included.
+            None if (8..=15).contains(&digits.len()) && !digits.starts_with('0') => return Ok(format!("+{}", digits)),
+            None => return Err(invalid()),
+        }
+    } else {
+        digits
+    };
+    // Portuguese numbers have nine digits: landlines start with 2, nomadic numbers with 3, mobiles with 9.
+    if national.len() == 9 && matches!(national.as_bytes()[0], b'2' | b'3' | b'9') {
+        Ok(format!("+{}{}", PORTUGAL_COUNTRY_CODE, national))
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn normalizes_portuguese_numbers_to_e164() {
+        assert_eq!(normalize_phone("912 345 678").unwrap(), "+351912345678");
+        assert_eq!(normalize_phone("21-234-5678").unwrap(), "+351212345678");
+        assert_eq!(normalize_phone("+351 912345678").unwrap(), "+351912345678");
+        assert_eq!(normalize_phone("00351912345678").unwrap(), "+351912345678");
+    }
+
+    #[test]
+    fn keeps_international_numbers_with_a_country_code() {
+        assert_eq!(normalize_phone("+33 6 12 34 56 78").unwrap(), "+33612345678");
+        assert_eq!(normalize_phone("0044 20 7946 0958").unwrap(), "+442079460958");
+    }
+
+    #[test]
+    fn rejects_malformed_phone_numbers() {
+        assert!(normalize_phone("12345").is_err());
+        assert!(normalize_phone("812345678").is_err());
+        assert!(normalize_phone("+351 12345").is_err());
+        assert!(normalize_phone("91234567a").is_err());
+        assert!(normalize_phone("+1234567890123456").is_err());
+    }
+
+    #[test]
+    fn checks_email_syntax() {
+        assert!(is_valid_email("ana.silva@example.pt"));
+        assert!(!is_valid_email("ana.silva@example"));
+        assert!(!is_valid_email("ana silva@example.pt"));
+        assert!(!is_valid_email("@example.pt"));
+        assert!(!is_valid_email("ana@@example.pt"));
+        assert!(!is_valid_email("ana@example..pt"));
+    }
+
+    #[test]
+    fn collects_every_field_error_and_trims_the_rest() {
+        let mut group = CreateConfirmationGroup {
+            module: 0,
+            catechist_id: None,
+            day_of_the_week: crate::models::DayOfTheWeek::Monday,
+            group_link: Some("  ".to_string()),
+            start_date: date(2026, 10, 1),
+            end_date: Some(date(2026, 9, 1)),
+            start_time: None,
+            end_time: None,
+            room_id: None,
+            catechetical_year_id: None,
+        };
+        let (status, body) = group.validate(date(2026, 6, 1)).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(body["errors"]["module"].is_array());
+        assert!(body["errors"]["end_date"].is_array());
+        assert_eq!(group.group_link, None);
+    }
+
+    #[test]
+    fn lists_every_unmet_sponsor_requirement() {
+        let mut sponsor = UpsertSponsor {
+            full_name: " ".to_string(),
+            email: None,
+            phone_number: None,
+            relationship: SponsorRelationship::Parent,
+            birth_date: date(2012, 1, 1),
+            is_confirmed: false,
+            has_first_communion: true,
+            marital_status: MaritalStatus::Union,
+            parish: Some(" Sé ".to_string()),
+        };
+        let (status, body) = sponsor.validate(date(2026, 6, 1)).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let fields: Vec<&String> = body["errors"].as_object().unwrap().keys().collect();
+        assert_eq!(fields, ["birth_date", "full_name", "is_confirmed", "marital_status", "relationship"]);
+        assert_eq!(sponsor.parish.as_deref(), Some("Sé"));
+    }
+
+    #[test]
+    fn keys_holiday_errors_by_the_fields_its_kind_needs() {
+        let mut easter = CreateParishHoliday {
+            name: " Páscoa ".to_string(),
+            kind: HolidayKind::EasterRelative,
+            start_date: None,
+            end_date: None,
+            easter_offset_start: Some(0),
+            easter_offset_end: None,
+        };
+        let (status, body) = easter.validate(date(2026, 6, 1)).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let fields: Vec<&String> = body["errors"].as_object().unwrap().keys().collect();
+        assert_eq!(fields, ["easter_offset_end"]);
+        assert_eq!(easter.name, "Páscoa");
+
+        let mut range = CreateParishHoliday {
+            kind: HolidayKind::Range,
+            start_date: Some(date(2026, 12, 31)),
+            end_date: Some(date(2026, 12, 24)),
+            ..easter
+        };
+        let (_, body) = range.validate(date(2026, 6, 1)).unwrap_err();
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(body["errors"]["end_date"].is_array());
+
+        let mut annual = CreateParishHoliday { kind: HolidayKind::Annual, ..range };
+        assert!(annual.validate(date(2026, 6, 1)).is_ok());
+    }
+
+    #[test]
+    fn keys_requirement_errors_by_position() {
+        let requirement = |kind, label: &str| CreateEligibilityRequirement {
+            kind,
+            label: label.to_string(),
+            sacrament_code: None,
+            module_count: None,
+            minimum_age: None,
+            enabled: true,
+        };
+        let mut checklist = vec![
+            requirement(RequirementKind::Sponsor, "Padrinho"),
+            requirement(RequirementKind::ModulesCompleted, " "),
+        ];
+        let (status, body) = checklist.validate(date(2026, 6, 1)).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let fields: Vec<&String> = body["errors"].as_object().unwrap().keys().collect();
+        assert_eq!(fields, ["[1].label", "[1].module_count"]);
+    }
+
+    #[test]
+    fn patches_keep_absent_fields_and_refuse_null_required_ones() {
+        let current = crate::models::Confirmand {
+            address: "Rua A".to_string(),
+            father_name: Some("Rui Silva".to_string()),
+            ..crate::models::Confirmand::sample(1, "Ana Silva", date(2010, 3, 4))
+        };
+        let patch = |body: &str| -> PatchConfirmand { serde_json::from_str(body).unwrap() };
+
+        let mut absent = patch("{}");
+        assert!(absent.validate(date(2026, 6, 1)).is_ok());
+        let applied = absent.apply_to(current.clone()).unwrap();
+        assert_eq!((applied.full_name.as_str(), applied.address.as_str()), ("Ana Silva", "Rua A"));
+        assert_eq!(applied.father_name.as_deref(), Some("Rui Silva"));
+
+        let mut null = patch(r#"{"full_name": null, "marital_status": null, "father_name": null}"#);
+        let (status, body) = null.validate(date(2026, 6, 1)).unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let fields: Vec<&String> = body["errors"].as_object().unwrap().keys().collect();
+        assert_eq!(fields, ["full_name", "marital_status"]);
+
+        let mut value = patch(r#"{"full_name": "Ana Sofia Silva", "father_name": null}"#);
+        assert!(value.validate(date(2026, 6, 1)).is_ok());
+        let applied = value.apply_to(current).unwrap();
+        assert_eq!(applied.full_name, "Ana Sofia Silva");
+        assert_eq!(applied.father_name, None);
+    }
+
+    #[tokio::test]
+    async fn sends_field_errors_as_json() {
+        use axum::response::IntoResponse;
+        let mut errors = FieldErrors::default();
+        errors.add("email", "is required");
+        let response = json_field_errors(errors.into_result().unwrap_err().into_response()).await;
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+        let other = (StatusCode::UNPROCESSABLE_ENTITY, "Failed to deserialize the JSON body".to_string()).into_response();
+        let response = json_field_errors(other).await;
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/plain; charset=utf-8");
+    }
+
+    #[test]
+    fn bounds_birth_dates() {
+        let mut errors = FieldErrors::default();
+        birth_date(&mut errors, "birth_date", date(2030, 1, 1), date(2026, 6, 1));
+        birth_date(&mut errors, "birth_date", date(1890, 1, 1), date(2026, 6, 1));
+        birth_date(&mut errors, "birth_date", date(2010, 1, 1), date(2026, 6, 1));
+        assert_eq!(errors.0["birth_date"].len(), 2);
+    }
+}
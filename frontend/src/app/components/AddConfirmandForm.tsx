@@ -3,6 +3,8 @@
 import { useState, FormEvent } from 'react';
 import { Confirmand } from '@/types';
 import { useApiClient } from '@/lib/useApiClient'; 
+import { ADULT_AGE, ageOn } from '@/lib/utils';
+import GuardianConsentFields, { emptyGuardianConsent } from './GuardianConsentFields';
 
 interface AddConfirmandFormProps {
   onConfirmandAdded: (newConfirmand: Confirmand) => void;
@@ -20,6 +22,10 @@ export default function AddConfirmandForm({ onConfirmandAdded }: AddConfirmandFo
   const [motherName, setMotherName] = useState('');
   const [baptismChurch, setBaptismChurch] = useState('');
   const [communionChurch, setCommunionChurch] = useState('');
+  const [guardianConsent, setGuardianConsent] = useState(emptyGuardianConsent());
+
+  const age = ageOn(birthDate);
+  const isMinor = age !== null && age < ADULT_AGE;
 
   const [error, setError] = useState<string | null>(null);
   const [isSubmitting, setIsSubmitting] = useState(false);
@@ -41,6 +47,8 @@ export default function AddConfirmandForm({ onConfirmandAdded }: AddConfirmandFo
       mother_name: motherName || null,
       baptism_church: baptismChurch || null,
       communion_church: communionChurch || null,
+      // Minors can only be registered with a guardian's consent.
+      guardian_consent: isMinor ? guardianConsent : undefined,
     };
 
     try {
@@ -57,6 +65,7 @@ export default function AddConfirmandForm({ onConfirmandAdded }: AddConfirmandFo
       setMotherName('');
       setBaptismChurch('');
       setCommunionChurch('');
+      setGuardianConsent(emptyGuardianConsent());
 
     } catch (err: unknown) {
       if (err instanceof Error) {
@@ -144,6 +153,10 @@ export default function AddConfirmandForm({ onConfirmandAdded }: AddConfirmandFo
             <input type="text" id="communionChurch" value={communionChurch} onChange={(e) => setCommunionChurch(e.target.value)} 
               className="mt-1 block w-full rounded-md border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700/50 text-gray-900 dark:text-gray-200 shadow-sm focus:border-indigo-500 focus:ring-indigo-500" />
         </div>
+
+        {isMinor && (
+          <GuardianConsentFields idPrefix="add" consent={guardianConsent} onChange={setGuardianConsent} required />
+        )}
       </div>
 
       {error && <p className="text-red-600 mt-4 text-sm">{error}</p>}
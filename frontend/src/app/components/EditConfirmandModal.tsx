@@ -3,6 +3,8 @@
 import { useState, FormEvent } from 'react';
 import { Confirmand } from '@/types';
 import { useApiClient } from '@/lib/useApiClient';
+import { ADULT_AGE, ageOn } from '@/lib/utils';
+import GuardianConsentFields, { emptyGuardianConsent } from './GuardianConsentFields';
 
 interface EditConfirmandModalProps {
   confirmand: Confirmand;
@@ -22,6 +24,11 @@ export default function EditConfirmandModal({ confirmand, onClose, onConfirmandU
   const [motherName, setMotherName] = useState(confirmand.mother_name || '');
   const [baptismChurch, setBaptismChurch] = useState(confirmand.baptism_church || '');
   const [communionChurch, setCommunionChurch] = useState(confirmand.communion_church || '');
+  const [guardianConsent, setGuardianConsent] = useState(emptyGuardianConsent());
+
+  // Minors saved without a consent on record are refused, so one can be entered here.
+  const age = ageOn(birthDate);
+  const isMinor = age !== null && age < ADULT_AGE;
 
   const [error, setError] = useState<string | null>(null);
   const [isSubmitting, setIsSubmitting] = useState(false);
@@ -43,10 +50,11 @@ export default function EditConfirmandModal({ confirmand, onClose, onConfirmandU
       mother_name: motherName || null,
       baptism_church: baptismChurch || null,
       communion_church: communionChurch || null,
+      guardian_consent: isMinor && guardianConsent.full_name ? guardianConsent : undefined,
     };
 
     try {
-      const updatedData = await api.put<Confirmand>(`/api/confirmands/${confirmand.id}`, updatedPayload);
+      const updatedData = await api.put<Confirmand>(`/api/confirmands/${confirmand.id}`, updatedPayload, confirmand.version);
       onConfirmandUpdated(updatedData);
       onClose();
 
@@ -143,6 +151,10 @@ export default function EditConfirmandModal({ confirmand, onClose, onConfirmandU
               <input type="text" id="editCommunionChurch" value={communionChurch} onChange={(e) => setCommunionChurch(e.target.value)} 
                 className="mt-1 block w-full rounded-md border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700/50 text-gray-900 dark:text-gray-200 shadow-sm focus:border-indigo-500 focus:ring-indigo-500" />
             </div>
+
+            {isMinor && (
+              <GuardianConsentFields idPrefix="edit" consent={guardianConsent} onChange={setGuardianConsent} required={false} />
+            )}
           </div>
 
           {error && <p className="text-red-600 mt-4 text-sm">{error}</p>}
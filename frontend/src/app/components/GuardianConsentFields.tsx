@@ -0,0 +1,75 @@
+'use client';
+
+import { GuardianConsentPayload } from '@/types';
+
+export const emptyGuardianConsent = (): GuardianConsentPayload => ({
+  full_name: '',
+  phone_number: null,
+  email: null,
+  relationship: 'Mother',
+  given_date: new Date().toISOString().slice(0, 10),
+  document: null,
+});
+
+interface GuardianConsentFieldsProps {
+  idPrefix: string;
+  consent: GuardianConsentPayload;
+  onChange: (consent: GuardianConsentPayload) => void;
+  // Whether a consent must be entered here, or one may already be on record.
+  required: boolean;
+}
+
+const inputClassName = "mt-1 block w-full rounded-md border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700/50 text-gray-900 dark:text-gray-200 shadow-sm focus:border-indigo-500 focus:ring-indigo-500";
+const labelClassName = "block text-sm font-medium text-gray-700 dark:text-gray-300";
+
+// The guardian giving a minor's participation consent, sent as `guardian_consent`.
+export default function GuardianConsentFields({ idPrefix, consent, onChange, required }: GuardianConsentFieldsProps) {
+  const set = (changes: Partial<GuardianConsentPayload>) => onChange({ ...consent, ...changes });
+
+  return (
+    <>
+      <h3 className="md:col-span-2 text-lg font-medium text-gray-900 dark:text-gray-200 border-b border-gray-200 dark:border-gray-700 pb-2 mt-4">
+        Guardian Consent {required ? '(Required for minors)' : '(Only if none is on record yet)'}
+      </h3>
+
+      <div>
+        <label htmlFor={`${idPrefix}GuardianName`} className={labelClassName}>Guardian&apos;s Full Name</label>
+        <input type="text" id={`${idPrefix}GuardianName`} value={consent.full_name || ''} onChange={(e) => set({ full_name: e.target.value })} required={required}
+          className={inputClassName} />
+      </div>
+      <div>
+        <label htmlFor={`${idPrefix}GuardianRelationship`} className={labelClassName}>Relationship</label>
+        <select id={`${idPrefix}GuardianRelationship`} value={consent.relationship} onChange={(e) => set({ relationship: e.target.value })}
+          className={inputClassName}>
+          <option value="Mother">Mother</option>
+          <option value="Father">Father</option>
+          <option value="Legal guardian">Legal guardian</option>
+          <option value="Grandparent">Grandparent</option>
+          <option value="Sibling">Sibling</option>
+          <option value="Uncle/Aunt">Uncle/Aunt</option>
+          <option value="Other">Other</option>
+        </select>
+      </div>
+      <div>
+        <label htmlFor={`${idPrefix}GuardianPhone`} className={labelClassName}>Guardian&apos;s Phone Number</label>
+        <input type="tel" id={`${idPrefix}GuardianPhone`} value={consent.phone_number || ''} onChange={(e) => set({ phone_number: e.target.value || null })}
+          className={inputClassName} />
+      </div>
+      <div>
+        <label htmlFor={`${idPrefix}GuardianEmail`} className={labelClassName}>Guardian&apos;s Email Address</label>
+        <input type="email" id={`${idPrefix}GuardianEmail`} value={consent.email || ''} onChange={(e) => set({ email: e.target.value || null })}
+          className={inputClassName} />
+      </div>
+      <div>
+        <label htmlFor={`${idPrefix}ConsentDate`} className={labelClassName}>Consent Given On</label>
+        <input type="date" id={`${idPrefix}ConsentDate`} value={consent.given_date} onChange={(e) => set({ given_date: e.target.value })} required={required}
+          className={inputClassName} />
+      </div>
+      <div>
+        <label htmlFor={`${idPrefix}ConsentDocument`} className={labelClassName}>Signed Form Reference</label>
+        <input type="text" id={`${idPrefix}ConsentDocument`} value={consent.document || ''} onChange={(e) => set({ document: e.target.value || null })}
+          className={inputClassName} />
+      </div>
+    </>
+  );
+}
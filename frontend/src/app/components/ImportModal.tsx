@@ -55,7 +55,7 @@ export default function ImportModal({ onClose, onImportSuccess }: ImportModalPro
         throw new Error(result.error || 'An unknown error occurred during import.');
       }
 
-      setSuccessMessage(`Import successful! ${result.new_participants_imported} new participants added. ${result.rows_skipped} rows skipped.`);
+      setSuccessMessage(`Import successful! ${result.new_participants_imported} new participants added. ${result.rows_skipped} rows skipped${result.minors_skipped ? ` (${result.minors_skipped} minors, who need a guardian's consent and must be added by hand)` : ''}.`);
       onImportSuccess(result.imported_records || []);
       setFile(null);
       if (fileInputRef.current) {
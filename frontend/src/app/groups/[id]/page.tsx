@@ -93,7 +93,7 @@ export default function GroupDetailPage() {
   const handleRemoveParticipant = async (participantId: number) => {
     if (!api || !window.confirm("Are you sure you want to remove this participant from the group?")) return;
     try {
-        await api.delete(`/api/groups/${groupId}/participants/${participantId}`);
+        await api.delete(`/api/groups/${groupId}/participants/${participantId}`, groupDetails?.version);
         if (groupDetails) {
             setGroupDetails({
                 ...groupDetails,
@@ -3,7 +3,7 @@
 import { useState, useEffect, ChangeEvent } from 'react';
 import { useParams } from 'next/navigation';
 import Link from 'next/link';
-import { ConfirmandDetails, Sacrament } from '@/types';
+import { ConfirmandDetails, Sacrament, SacramentRecord } from '@/types';
 import { getGroupLabel } from '@/lib/utils';
 import { useApiClient } from '@/lib/useApiClient'; // We were missing this hook call
 
@@ -55,7 +55,13 @@ export default function ParticipantDetailPage() {
     const changedSacrament = allSacraments.find(s => s.id === sacramentId);
     if (changedSacrament) {
       if (isChecked) {
-        setDetails({ ...details, sacraments: [...details.sacraments, changedSacrament].sort((a,b) => a.id - b.id) });
+        // The register details are filled in later, so the new record starts without them
+        const newRecord: SacramentRecord = {
+          ...changedSacrament,
+          received_date: null, church: null, parish: null, officiant: null,
+          register_book: null, register_page: null, register_entry: null,
+        };
+        setDetails({ ...details, sacraments: [...details.sacraments, newRecord].sort((a,b) => a.id - b.id) });
       } else {
         setDetails({ ...details, sacraments: details.sacraments.filter(s => s.id !== sacramentId) });
       }
@@ -66,8 +72,11 @@ export default function ParticipantDetailPage() {
       if (isChecked) {
         await api.post(`/api/confirmands/${participantId}/sacraments`, { sacrament_id: sacramentId });
       } else {
-        await api.delete(`/api/confirmands/${participantId}/sacraments/${sacramentId}`);
+        await api.delete(`/api/confirmands/${participantId}/sacraments/${sacramentId}`, details.version);
       }
+      // Sacrament records are part of the participant, so their version has moved on
+      const refreshed = await api.get<ConfirmandDetails>(`/api/confirmands/${participantId}/details`);
+      setDetails(refreshed);
     } catch (_err: unknown) {
       setError('Error updating sacrament. Please refresh and try again.');
       // NOTE: You could add logic here to revert the optimistic UI change.
@@ -78,16 +78,16 @@ export default function ParticipantsPage() {
     );
   };
 
-  const handleDelete = async (id: number) => {
-    if (!api || !window.confirm('Are you sure you want to delete this participant? This action cannot be undone.')) return;
+  const handleDelete = async (id: number, version: number) => {
+    if (!api || !window.confirm('Archive this participant? Archived participants are hidden from lists but can be restored.')) return;
     try {
-      await api.delete(`/api/confirmands/${id}`);
+      await api.delete(`/api/confirmands/${id}`, version);
       setConfirmands((prev) => prev.filter((c) => c.id !== id));
     } catch (err: unknown) {
       if (err instanceof Error) {
         setError(err.message);
       } else {
-        setError("An unknown error occurred while deleting the participant.");
+        setError("An unknown error occurred while archiving the participant.");
       }
     }
   };
@@ -172,7 +172,7 @@ export default function ParticipantsPage() {
                       <button onClick={() => setEditingConfirmand(c)} className="font-medium text-indigo-600 dark:text-indigo-400 hover:underline">
                         Edit
                       </button>
-                      <button onClick={() => handleDelete(c.id)} className="font-medium text-red-600 dark:text-red-400 hover:underline">
+                      <button onClick={() => handleDelete(c.id, c.version)} className="font-medium text-red-600 dark:text-red-400 hover:underline">
                         Delete
                       </button>
                     </td>
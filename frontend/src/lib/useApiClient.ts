@@ -2,6 +2,11 @@
 
 import { useCallback, useMemo } from 'react';
 
+// Confirmands, catechists and groups can only be changed by naming the version that was read.
+function ifMatch(version?: number): Record<string, string> {
+  return version === undefined ? {} : { 'If-Match': `"${version}"` };
+}
+
 // This custom hook provides a simple, consistent way to make API calls.
 export function useApiClient() {
 
@@ -15,7 +20,13 @@ export function useApiClient() {
       } catch (e) {
         errorBody = await response.text();
       }
-      const errorMessage = errorBody?.error || errorBody || `Request failed with status ${response.status}`;
+      // Validation failures come back as `{"errors": {"field": ["message", ...]}}`.
+      const fieldErrors = errorBody?.errors
+        ? Object.entries(errorBody.errors as Record<string, string[]>)
+            .map(([field, messages]) => `${field} ${messages.join(', ')}`)
+            .join('; ')
+        : null;
+      const errorMessage = fieldErrors || errorBody?.error || errorBody || `Request failed with status ${response.status}`;
       throw new Error(errorMessage);
     }
     return response;
@@ -44,10 +55,11 @@ export function useApiClient() {
       return JSON.parse(text);
     },
 
-    put: async <T>(url: string, body: any): Promise<T> => {
+    // `version` is the record's version as last read; the server refuses the write if it has changed since.
+    put: async <T>(url: string, body: any, version?: number): Promise<T> => {
       const response = await fetcher(url, {
         method: 'PUT',
-        headers: { 'Content-Type': 'application/json' },
+        headers: { 'Content-Type': 'application/json', ...ifMatch(version) },
         body: JSON.stringify(body),
       });
       const text = await response.text();
@@ -58,8 +70,8 @@ export function useApiClient() {
     },
     // --- END FIX ---
 
-    delete: async (url: string): Promise<Response> => {
-      return fetcher(url, { method: 'DELETE' });
+    delete: async (url: string, version?: number): Promise<Response> => {
+      return fetcher(url, { method: 'DELETE', headers: ifMatch(version) });
     },
   }), [fetcher]);
 
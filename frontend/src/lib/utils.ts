@@ -10,4 +10,15 @@ export const getGroupLabel = (startDate: string): string => {
   const semester = month < 6 ? "1st" : "2nd";
 
   return `${year} ${semester} Semester`;
-};
\ No newline at end of file
+};
+
+// Age of majority; younger participants need a guardian's consent (same rule as the API).
+export const ADULT_AGE = 18;
+
+// Age in whole years on `today` for a "YYYY-MM-DD" birth date, or null while it is incomplete.
+export const ageOn = (birthDate: string, today: Date = new Date()): number | null => {
+  const [year, month, day] = birthDate.split('-').map(Number);
+  if (!year || !month || !day) return null;
+  const hadBirthday = today.getMonth() + 1 > month || (today.getMonth() + 1 === month && today.getDate() >= day);
+  return today.getFullYear() - year - (hadBirthday ? 0 : 1);
+};
@@ -8,6 +8,7 @@ export interface GroupSummary {
   id: number;
   module: number;
   start_date: string; // "YYYY-MM-DD"
+  end_date: string | null;
   catechist_name: string | null;
 }
 
@@ -29,6 +30,10 @@ export interface Confirmand {
   current_group_id: number | null;
   current_group_module: number | null;
   current_group_start_date: string | null;
+  age: number;
+  is_minor: boolean;
+  version: number; // Sent back in `If-Match` when updating or deleting
+  archived_at: string | null;
 }
 
 // Catechist type (unchanged)
@@ -39,6 +44,10 @@ export interface Catechist {
   latest_group_id: number | null;
   latest_group_module: number | null;
   latest_group_start_date: string | null; // This will be a "YYYY-MM-DD" string
+  max_concurrent_groups: number | null;
+  active_group_count: number;
+  total_member_count: number;
+  version: number;
 }
 
 export interface CatechistDetails extends Catechist {
@@ -55,6 +64,12 @@ export interface ConfirmationGroup {
   group_link: string | null;
   start_date: string;
   end_date: string | null;
+  start_time: string | null; // "HH:MM:SS"
+  end_time: string | null;
+  room_id: number | null;
+  room_name: string | null;
+  catechetical_year_id: number | null;
+  version: number;
 }
 
 // Group Details Type (unchanged)
@@ -64,17 +79,126 @@ export interface ConfirmationGroupDetails {
   catechist_name: string | null;
   day_of_the_week: string;
   start_date: string;
+  start_time: string | null;
+  end_time: string | null;
+  room_id: number | null;
+  room_name: string | null;
+  version: number;
+  session_rooms: SessionRoom[];
   members: Confirmand[];
 }
 
+// A single group session moved to a different room
+export interface SessionRoom {
+  session_date: string;
+  room_id: number;
+  room_name: string;
+}
+
 // Sacrament Type (unchanged)
 export interface Sacrament {
     id: number;
     name: string;
+    code: string | null;
+}
+
+// A sacrament received by a participant, with the parish register details
+export interface SacramentRecord extends Sacrament {
+    received_date: string | null;
+    church: string | null;
+    parish: string | null;
+    officiant: string | null;
+    register_book: string | null;
+    register_page: string | null;
+    register_entry: string | null;
 }
 
 // Participant Detail Type - NOW INCLUDES GROUP HISTORY
 export interface ConfirmandDetails extends Confirmand {
-    sacraments: Sacrament[];
+    sacraments: SacramentRecord[];
     group_history: GroupSummary[]; // --- NEW ---
-}
\ No newline at end of file
+    sponsor: Sponsor | null;
+    guardians: Guardian[];
+    household: Household | null;
+}
+
+export interface Sponsor {
+    id: number;
+    confirmand_id: number;
+    full_name: string;
+    email: string | null;
+    phone_number: string | null;
+    relationship: string;
+    birth_date: string; // "YYYY-MM-DD"
+    is_confirmed: boolean;
+    has_first_communion: boolean;
+    marital_status: string;
+    parish: string | null;
+}
+
+export interface Guardian {
+    id: number;
+    full_name: string;
+    phone_number: string | null;
+    email: string | null;
+    relationship: string;
+    is_primary_contact: boolean;
+    is_consent_holder: boolean;
+}
+
+export interface Household {
+    id: number;
+    name: string;
+    address: string | null;
+    phone_number: string | null;
+    email: string | null;
+    confirmand_count: number;
+}
+
+// Only readable by roles granted access to sensitive data.
+export interface EmergencyInfo {
+    emergency_contact_name: string | null;
+    emergency_contact_phone: string | null;
+    allergies: string | null;
+    medical_notes: string | null;
+}
+
+export interface Consent {
+    id: number;
+    confirmand_id: number;
+    guardian_id: number;
+    guardian_name: string;
+    kind: string;
+    given_date: string; // "YYYY-MM-DD"
+    document: string | null;
+}
+
+// Sent as `guardian_consent` when saving a minor who has no participation consent on record yet.
+// Either an existing guardian (`guardian_id`) or a new one (`full_name` and contact data).
+export interface GuardianConsentPayload {
+    guardian_id?: number;
+    full_name?: string;
+    phone_number?: string | null;
+    email?: string | null;
+    relationship: string;
+    given_date: string; // "YYYY-MM-DD"
+    document: string | null;
+}
+
+// One entry of a participant's timeline (`/api/confirmands/:id/history`).
+export interface HistoryEvent {
+    date: string; // "YYYY-MM-DD"
+    recorded_at: string | null;
+    kind: 'Registered' | 'FieldsChanged' | 'Archived' | 'Restored' | 'Enrolled' | 'Transferred' | 'LeftGroup' | 'ModuleCompleted' | 'SacramentReceived';
+    summary: string;
+    changes: { field: string; before: unknown; after: unknown }[];
+    username: string | null;
+}
+
+// Two participants who are probably the same person (`/api/confirmands/duplicates`).
+export interface DuplicateCandidate {
+    first: Confirmand;
+    second: Confirmand;
+    score: number;
+    reasons: string[];
+}